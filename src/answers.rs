@@ -0,0 +1,228 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	runner::{DayResult, Part, PartResult},
+	Answer,
+};
+
+pub const DEFAULT_ANSWERS_PATH: &str = "answers.toml";
+
+/// Known-correct answers, stored as TOML with a `[day<N>]` table for each day:
+///
+/// ```toml
+/// [day1]
+/// part1 = 24000
+/// part2 = 45000
+///
+/// [day5]
+/// part1 = "CMZ"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+	days: BTreeMap<u8, DayAnswers>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	part1: Option<Expected>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	part2: Option<Expected>,
+}
+
+/// An [`Answer`] as written in TOML, without the type tag.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+enum Expected {
+	Integer(i64),
+	String(String),
+	Grid(Vec<String>),
+}
+
+impl From<Expected> for Answer {
+	fn from(value: Expected) -> Self {
+		match value {
+			Expected::Integer(value) => Self::Integer(value),
+			Expected::String(value) => Self::Text(value),
+			Expected::Grid(rows) => Self::Grid(rows),
+		}
+	}
+}
+
+impl TryFrom<Answer> for Expected {
+	/// Unsolved answers can't be expected
+	type Error = Answer;
+
+	fn try_from(value: Answer) -> Result<Self, Self::Error> {
+		match value {
+			Answer::Integer(value) => Ok(Self::Integer(value)),
+			Answer::Text(value) | Answer::Letters { text: value, .. } => Ok(Self::String(value)),
+			Answer::Grid(rows) => Ok(Self::Grid(rows)),
+			Answer::Unsolved(_) => Err(value),
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+	Match,
+	Mismatch {
+		expected: Answer,
+	},
+	/// No expected answer is recorded
+	Missing,
+}
+
+impl Answers {
+	pub fn parse(text: &str) -> Result<Self, String> {
+		let tables: BTreeMap<String, DayAnswers> =
+			toml::from_str(text).map_err(|err| err.to_string())?;
+
+		let days = tables
+			.into_iter()
+			.map(|(key, answers)| {
+				key.strip_prefix("day")
+					.and_then(|day| day.parse::<u8>().ok())
+					.map(|day| (day, answers))
+					.ok_or(format!("Expected a `[day<N>]` table, found `[{key}]`"))
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self { days })
+	}
+
+	pub fn expected(&self, day: u8, part: Part) -> Option<Answer> {
+		let answers = self.days.get(&day)?;
+		let expected = match part {
+			Part::One => &answers.part1,
+			Part::Two => &answers.part2,
+		};
+
+		expected.clone().map(Answer::from)
+	}
+
+	pub fn check(&self, day: u8, result: &PartResult) -> Outcome {
+		// Letters read from a grid are compared as plain text
+		let actual = Expected::try_from(result.answer.clone()).map(Answer::from);
+
+		match self.expected(day, result.part) {
+			Some(expected) if actual.as_ref() == Ok(&expected) => Outcome::Match,
+			Some(expected) => Outcome::Mismatch { expected },
+			None => Outcome::Missing,
+		}
+	}
+
+	/// Records every solved answer in `result` as the expected answer.
+	pub fn record(&mut self, result: &DayResult) {
+		let answers = self.days.entry(result.day).or_default();
+
+		for part in &result.parts {
+			let Ok(expected) = Expected::try_from(part.answer.clone()) else {
+				continue;
+			};
+			let expected = Some(expected);
+			match part.part {
+				Part::One => answers.part1 = expected,
+				Part::Two => answers.part2 = expected,
+			}
+		}
+	}
+
+	/// Renders the answers as TOML, with days in numeric order.
+	pub fn to_toml(&self) -> Result<String, String> {
+		let mut text = String::new();
+
+		for (day, answers) in &self.days {
+			if !text.is_empty() {
+				text.push('\n');
+			}
+
+			let table = toml::to_string(answers).map_err(|err| err.to_string())?;
+			let _ = write!(text, "[day{day}]\n{table}");
+		}
+
+		Ok(text)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+
+	use crate::{
+		answers::{Answers, Outcome},
+		runner::{Part, PartResult},
+		Answer,
+	};
+
+	const TEST_ANSWERS: &str = r##"[day1]
+part1 = 24000
+part2 = 45000
+
+[day5]
+part1 = "CMZ"
+
+[day10]
+part2 = [".##.", "#..#"]
+"##;
+
+	fn result(part: Part, answer: Answer) -> PartResult {
+		PartResult {
+			part,
+			answer,
+			elapsed: Duration::ZERO,
+			grid: None,
+		}
+	}
+
+	#[test]
+	fn test_check() {
+		let answers = Answers::parse(TEST_ANSWERS).unwrap();
+
+		assert_eq!(
+			answers.check(1, &result(Part::One, Answer::Integer(24000))),
+			Outcome::Match
+		);
+		assert_eq!(
+			answers.check(1, &result(Part::Two, Answer::Integer(1))),
+			Outcome::Mismatch {
+				expected: Answer::Integer(45000)
+			}
+		);
+		assert_eq!(
+			answers.check(5, &result(Part::Two, Answer::Text("MCD".to_owned()))),
+			Outcome::Missing
+		);
+		assert_eq!(
+			answers.check(
+				5,
+				&result(
+					Part::One,
+					Answer::Letters {
+						text: "CMZ".to_owned(),
+						rows: Vec::new()
+					}
+				)
+			),
+			Outcome::Match
+		);
+		assert_eq!(
+			answers.expected(10, Part::Two),
+			Some(Answer::Grid(vec![".##.".to_owned(), "#..#".to_owned()]))
+		);
+	}
+
+	#[test]
+	fn test_round_trip() {
+		let answers = Answers::parse(TEST_ANSWERS).unwrap();
+
+		assert_eq!(
+			Answers::parse(&answers.to_toml().unwrap()).unwrap(),
+			answers
+		);
+		assert!(Answers::parse("[one]\npart1 = 1\n").is_err());
+	}
+}
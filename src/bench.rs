@@ -0,0 +1,183 @@
+use std::{
+	hint::black_box,
+	time::{Duration, Instant},
+};
+
+use crate::{Answer, ParseError, Solution};
+
+/// Marks the start of the generated timings table in the README.
+pub const README_START: &str = "<!-- timings:start -->";
+/// Marks the end of the generated timings table in the README.
+pub const README_END: &str = "<!-- timings:end -->";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchOptions {
+	/// Untimed runs before sampling starts
+	pub warmup: usize,
+	/// Timed runs of each stage
+	pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+	fn default() -> Self {
+		Self {
+			warmup: 5,
+			iterations: 50,
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+	pub min: Duration,
+	pub median: Duration,
+	pub p95: Duration,
+}
+
+impl Stats {
+	/// Summarises a non-empty set of samples.
+	pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+		assert!(!samples.is_empty(), "Should have at least one sample");
+		samples.sort_unstable();
+
+		// Nearest-rank percentile
+		let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+
+		Self {
+			min: samples[0],
+			median: percentile(50),
+			p95: percentile(95),
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DayBench {
+	pub day: u8,
+	pub parse: Stats,
+	pub part1: Stats,
+	pub part2: Stats,
+}
+
+/// Times parsing and each part separately, every stage being run `options.iterations` times.
+pub fn bench<S: Solution>(input: &str, options: &BenchOptions) -> Result<DayBench, ParseError> {
+	let parsed = S::parse(input)?;
+
+	Ok(DayBench {
+		day: S::DAY,
+		parse: sample(options, || S::parse(input)),
+		part1: sample(options, || -> Answer { S::part1(&parsed).into() }),
+		part2: sample(options, || -> Answer { S::part2(&parsed).into() }),
+	})
+}
+
+fn sample<T>(options: &BenchOptions, mut f: impl FnMut() -> T) -> Stats {
+	for _ in 0..options.warmup {
+		black_box(f());
+	}
+
+	let samples = (0..options.iterations.max(1))
+		.map(|_| {
+			let start = Instant::now();
+			black_box(f());
+			start.elapsed()
+		})
+		.collect();
+
+	Stats::from_samples(samples)
+}
+
+/// Renders the README timings table, using the median of each stage.
+pub fn readme_table(benches: &[DayBench], options: &BenchOptions) -> String {
+	let header = format!(
+		"Median of {} runs after {} warmup runs on `{}-{}`.",
+		options.iterations,
+		options.warmup,
+		std::env::consts::OS,
+		std::env::consts::ARCH,
+	);
+
+	let rows = benches
+		.iter()
+		.map(|bench| {
+			let day = format!(
+				"[Day {day}](https://adventofcode.com/2022/day/{day})",
+				day = bench.day
+			);
+			let cells = [bench.parse, bench.part1, bench.part2]
+				.map(|stats| format!("`{:>8.3} ms`", stats.median.as_secs_f64() * 1000f64));
+
+			(day, cells)
+		})
+		.collect::<Vec<_>>();
+
+	let day_width = rows
+		.iter()
+		.map(|(day, _)| day.len())
+		.max()
+		.unwrap_or_default();
+	let cell_width = "`   0.000 ms`".len();
+
+	let mut table = vec![
+		header,
+		String::new(),
+		format!(
+			"| {:day_width$} | {:cell_width$} | {:cell_width$} | {:cell_width$} |",
+			"", "Parse", "Part 1", "Part 2"
+		),
+		format!(
+			"|:{}|:{}|:{}|:{}|",
+			"-".repeat(day_width + 1),
+			"-".repeat(cell_width + 1),
+			"-".repeat(cell_width + 1),
+			"-".repeat(cell_width + 1)
+		),
+	];
+
+	table.extend(rows.iter().map(|(day, [parse, part1, part2])| {
+		format!("| {day:day_width$} | {parse} | {part1} | {part2} |")
+	}));
+
+	table.join("\n")
+}
+
+/// Replaces the table between [`README_START`] and [`README_END`], returning `None` if the
+/// markers are missing.
+pub fn update_readme(readme: &str, table: &str) -> Option<String> {
+	let start = readme.find(README_START)? + README_START.len();
+	let end = start + readme[start..].find(README_END)?;
+
+	Some(format!("{}\n{table}\n{}", &readme[..start], &readme[end..]))
+}
+
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+
+	use crate::bench::{update_readme, Stats};
+
+	#[test]
+	fn test_stats() {
+		let samples = (1..=20).rev().map(Duration::from_millis).collect();
+
+		assert_eq!(
+			Stats::from_samples(samples),
+			Stats {
+				min: Duration::from_millis(1),
+				median: Duration::from_millis(10),
+				p95: Duration::from_millis(19),
+			}
+		);
+	}
+
+	#[test]
+	fn test_update_readme() {
+		let readme = "# Title\n<!-- timings:start -->\nold\n<!-- timings:end -->\nfooter\n";
+
+		assert_eq!(
+			update_readme(readme, "new").as_deref(),
+			Some("# Title\n<!-- timings:start -->\nnew\n<!-- timings:end -->\nfooter\n")
+		);
+		assert_eq!(update_readme("# Title\n", "new"), None);
+	}
+}
@@ -0,0 +1,81 @@
+use std::{path::PathBuf, time::Duration};
+
+use aoc2022::bench::{self, BenchOptions, Stats};
+use clap::Args;
+
+use crate::{DayArgs, InputArgs};
+
+#[derive(Args)]
+pub struct BenchArgs {
+	#[command(flatten)]
+	days: DayArgs,
+
+	/// Untimed runs of each stage before sampling
+	#[arg(long, default_value_t = BenchOptions::default().warmup)]
+	warmup: usize,
+
+	/// Timed runs of each stage
+	#[arg(long, default_value_t = BenchOptions::default().iterations)]
+	iterations: usize,
+
+	/// Rewrite the timings table in this README
+	#[arg(long, conflicts_with = "day", num_args = 0..=1, default_missing_value = "README.md")]
+	update_readme: Option<PathBuf>,
+
+	#[command(flatten)]
+	inputs: InputArgs,
+}
+
+pub fn bench(args: BenchArgs) -> Result<(), String> {
+	let options = BenchOptions {
+		warmup: args.warmup,
+		iterations: args.iterations,
+	};
+
+	println!("Day  Stage   Min (ms)  Median (ms)  P95 (ms)");
+
+	let mut benches = Vec::new();
+	for day in args.days.days()? {
+		let input = args.inputs.read(day)?;
+
+		let result = day
+			.bench(&input, &options)
+			.map_err(|err| args.inputs.diagnostic(day, err))?;
+
+		for (stage, stats) in [
+			("parse", result.parse),
+			("part 1", result.part1),
+			("part 2", result.part2),
+		] {
+			print_stats(day.number, stage, &stats);
+		}
+
+		benches.push(result);
+	}
+
+	if let Some(path) = args.update_readme {
+		let readme =
+			std::fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+		let readme = bench::update_readme(&readme, &bench::readme_table(&benches, &options))
+			.ok_or(format!(
+				"{}: missing `{}` and `{}` markers",
+				path.display(),
+				bench::README_START,
+				bench::README_END
+			))?;
+		std::fs::write(&path, readme).map_err(|err| format!("{}: {err}", path.display()))?;
+	}
+
+	Ok(())
+}
+
+fn print_stats(day: u8, stage: &str, stats: &Stats) {
+	let ms = |duration: Duration| duration.as_secs_f64() * 1000f64;
+
+	println!(
+		"{day:>3}  {stage:<6}  {:>8.3}  {:>11.3}  {:>8.3}",
+		ms(stats.min),
+		ms(stats.median),
+		ms(stats.p95)
+	);
+}
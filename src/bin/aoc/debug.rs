@@ -0,0 +1,69 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use aoc2022::{
+	day10::{
+		debugger::{Command, Debugger},
+		Day10,
+	},
+	input::InputSource,
+	runner, Solution,
+};
+use clap::Args;
+
+use crate::{CrtArgs, InputArgs};
+
+#[derive(Args)]
+pub struct DebugArgs {
+	#[command(flatten)]
+	inputs: InputArgs,
+
+	#[command(flatten)]
+	crt: CrtArgs,
+}
+
+pub fn debug(args: DebugArgs) -> Result<(), String> {
+	if matches!(args.inputs.source(), InputSource::Stdin) {
+		return Err(
+			"Commands are read from stdin, so the program must be read from a file".to_owned(),
+		);
+	}
+
+	let day = runner::day(Day10::DAY).expect("Day 10 should be registered");
+	let input = args.inputs.read(day)?;
+	let program = Day10::parse(&input).map_err(|err| args.inputs.diagnostic(day, err))?;
+
+	let mut debugger = Debugger::new(&program, args.crt.config());
+	let stdin = io::stdin();
+	// Only prompt when a person is typing, so scripted output stays clean
+	let interactive = stdin.is_terminal();
+
+	loop {
+		if interactive {
+			print!("(day10) ");
+			io::stdout().flush().map_err(|err| err.to_string())?;
+		}
+
+		let mut line = String::new();
+		if stdin
+			.lock()
+			.read_line(&mut line)
+			.map_err(|err| err.to_string())?
+			== 0
+		{
+			break;
+		}
+
+		let line = line.trim();
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+
+		match line.parse::<Command>() {
+			Ok(Command::Quit) => break,
+			Ok(command) => print!("{}", debugger.execute(command)),
+			Err(err) => eprintln!("{err}"),
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,246 @@
+use std::path::PathBuf;
+
+use aoc2022::{
+	day7::{
+		generate::{self, Options, Order, RandomSpec, Tree},
+		human_size, Cleanup, Day7, Dirs,
+	},
+	runner::{self, Day},
+	Solution,
+};
+use clap::{Args, Subcommand, ValueEnum};
+
+use crate::InputArgs;
+
+#[derive(Subcommand)]
+pub enum FsCommand {
+	/// List every directory and file like the puzzle statement
+	Tree {
+		#[command(flatten)]
+		transcript: TranscriptArgs,
+	},
+	/// Total size of every directory, largest first
+	Du {
+		/// Print sizes in bytes instead of K, M and G
+		#[arg(long, short)]
+		bytes: bool,
+
+		#[command(flatten)]
+		transcript: TranscriptArgs,
+	},
+	/// The largest files
+	Largest {
+		/// Number of files to list
+		#[arg(long, short = 'n', default_value_t = 10)]
+		count: usize,
+
+		#[command(flatten)]
+		transcript: TranscriptArgs,
+	},
+	/// Find directories to delete to free up enough space
+	Cleanup {
+		/// Size of the disk
+		#[arg(long, default_value_t = Cleanup::default().capacity)]
+		capacity: usize,
+
+		/// Free space needed on the disk
+		#[arg(long, default_value_t = Cleanup::default().required)]
+		required: usize,
+
+		/// Delete several directories if that deletes less than the smallest single directory
+		#[arg(long)]
+		combine: bool,
+
+		#[command(flatten)]
+		transcript: TranscriptArgs,
+	},
+	/// Report every anomaly in the transcript, exiting with an error if there are any
+	Check {
+		#[command(flatten)]
+		inputs: InputArgs,
+	},
+	/// Write a transcript exploring a directory on disk, or a random tree
+	Generate {
+		/// Directory to explore
+		#[arg(required_unless_present = "random")]
+		path: Option<PathBuf>,
+
+		/// Explore a random tree instead of a directory
+		#[arg(long, conflicts_with = "path")]
+		random: bool,
+
+		/// Levels of directories below the root of the random tree
+		#[arg(long, conflicts_with = "path", default_value_t = RandomSpec::default().depth)]
+		depth: usize,
+
+		/// Most directories in any one random directory
+		#[arg(long, conflicts_with = "path", default_value_t = RandomSpec::default().max_dirs)]
+		max_dirs: usize,
+
+		/// Most files in any one random directory
+		#[arg(long, conflicts_with = "path", default_value_t = RandomSpec::default().max_files)]
+		max_files: usize,
+
+		/// Largest random file size, with every file empty if it's 0
+		#[arg(long, conflicts_with = "path", default_value_t = RandomSpec::default().max_size)]
+		max_size: usize,
+
+		/// Order to visit directories in
+		#[arg(long, value_enum, default_value_t = GenerateOrder::DepthFirst)]
+		order: GenerateOrder,
+
+		/// Directories to list again after every directory has been listed
+		#[arg(long, default_value_t = 0)]
+		revisits: usize,
+
+		/// Seed for the random tree, shuffling and revisits
+		#[arg(long, default_value_t = 0)]
+		seed: u64,
+	},
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum GenerateOrder {
+	/// Enter each directory as soon as its parent is listed, like the puzzle
+	DepthFirst,
+	/// List every directory at one level before the next
+	BreadthFirst,
+	/// Depth first, with directories and listings shuffled
+	Random,
+}
+
+impl From<GenerateOrder> for Order {
+	fn from(order: GenerateOrder) -> Self {
+		match order {
+			GenerateOrder::DepthFirst => Order::DepthFirst,
+			GenerateOrder::BreadthFirst => Order::BreadthFirst,
+			GenerateOrder::Random => Order::Random,
+		}
+	}
+}
+
+#[derive(Args)]
+pub struct TranscriptArgs {
+	/// Refuse transcripts with anomalies, like a `cd` into a directory that was never listed
+	#[arg(long)]
+	strict: bool,
+
+	#[command(flatten)]
+	inputs: InputArgs,
+}
+
+fn day7() -> &'static Day {
+	runner::day(Day7::DAY).expect("Day 7 should be registered")
+}
+
+impl TranscriptArgs {
+	/// Reads and parses the day 7 transcript.
+	fn load(&self) -> Result<Dirs, String> {
+		let input = self.inputs.read(day7())?;
+
+		let dirs = if self.strict {
+			Dirs::parse_strict(&input)
+		} else {
+			Dirs::try_from(input.as_str())
+		};
+
+		dirs.map_err(|err| self.inputs.diagnostic(day7(), err))
+	}
+}
+
+pub fn fs(command: FsCommand) -> Result<(), String> {
+	match command {
+		FsCommand::Tree { transcript } => print!("{}", transcript.load()?.tree()),
+		FsCommand::Du { bytes, transcript } => {
+			for (path, size) in transcript.load()?.du() {
+				let size = if bytes {
+					size.to_string()
+				} else {
+					human_size(size)
+				};
+				println!("{size}\t{path}");
+			}
+		}
+		FsCommand::Largest { count, transcript } => {
+			for (path, size) in transcript.load()?.largest_files(count) {
+				println!("{}\t{path}", human_size(size));
+			}
+		}
+		FsCommand::Cleanup {
+			capacity,
+			required,
+			combine,
+			transcript,
+		} => {
+			let dirs = transcript.load()?;
+			let cleanup = Cleanup { capacity, required };
+
+			let plan = if combine {
+				cleanup.smallest_set(&dirs)
+			} else {
+				cleanup.smallest_dir(&dirs)
+			};
+			let plan = plan.ok_or(format!(
+				"Deleting everything doesn't free {}",
+				human_size(cleanup.to_free(&dirs))
+			))?;
+
+			for (path, size) in &plan.dirs {
+				println!("{}	{path}", human_size(*size));
+			}
+			println!(
+				"Frees {} of the {} bytes needed",
+				plan.freed,
+				cleanup.to_free(&dirs)
+			);
+		}
+		FsCommand::Check { inputs } => {
+			let input = inputs.read(day7())?;
+			let (_, anomalies) =
+				Dirs::parse(&input).map_err(|err| inputs.diagnostic(day7(), err))?;
+
+			for anomaly in &anomalies {
+				eprintln!("warning: {}\n", inputs.diagnostic(day7(), anomaly.clone()));
+			}
+
+			match anomalies.len() {
+				0 => println!("No anomalies found"),
+				1 => return Err("1 anomaly found".to_owned()),
+				count => return Err(format!("{count} anomalies found")),
+			}
+		}
+		FsCommand::Generate {
+			path,
+			random,
+			depth,
+			max_dirs,
+			max_files,
+			max_size,
+			order,
+			revisits,
+			seed,
+		} => {
+			let tree = if random {
+				let spec = RandomSpec {
+					depth,
+					max_dirs,
+					max_files,
+					max_size,
+				};
+				Tree::random(&spec, seed)
+			} else {
+				let path = path.ok_or("Expected a directory to explore, or --random")?;
+				Tree::read(&path).map_err(|err| format!("{}: {err}", path.display()))?
+			};
+
+			let options = Options {
+				order: order.into(),
+				revisits,
+				seed,
+			};
+			print!("{}", generate::transcript(&tree, &options));
+		}
+	}
+
+	Ok(())
+}
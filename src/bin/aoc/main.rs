@@ -0,0 +1,155 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc2022::{
+	day10::CrtConfig,
+	input::{InputSource, DEFAULT_INPUTS_DIR, INPUTS_DIR_ENV},
+	runner::{self, Day},
+	ParseError,
+};
+use clap::{Args, Parser, Subcommand};
+
+mod bench;
+mod debug;
+mod fs;
+mod run;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Run the solution for a single day, or for every day
+	Run(run::RunArgs),
+	/// Benchmark parsing and both parts of a single day, or of every day
+	Bench(bench::BenchArgs),
+	/// Check answers against the expected answers, exiting with an error on any mismatch
+	Verify(verify::VerifyArgs),
+	/// Inspect the filesystem reconstructed from a day 7 terminal transcript
+	#[command(subcommand)]
+	Fs(fs::FsCommand),
+	/// Step through a day 10 program, reading commands like `step`, `break cycle 20`, `break x > 5`
+	/// and `continue` from stdin
+	Debug(debug::DebugArgs),
+}
+
+#[derive(Args)]
+struct DayArgs {
+	/// Day to run
+	#[arg(required_unless_present = "all", conflicts_with = "all")]
+	day: Option<u8>,
+
+	/// Run every day in order
+	#[arg(long, conflicts_with = "input")]
+	all: bool,
+}
+
+impl DayArgs {
+	fn days(&self) -> Result<Vec<&'static Day>, String> {
+		match self.day {
+			Some(number) => Ok(vec![
+				runner::day(number).ok_or(format!("no solution for day {number}"))?
+			]),
+			None => Ok(runner::DAYS.iter().collect()),
+		}
+	}
+}
+
+#[derive(Args)]
+struct InputArgs {
+	/// Read the puzzle input from this file instead of the inputs directory, `-` reads stdin
+	#[arg(long, short)]
+	input: Option<PathBuf>,
+
+	/// Directory containing a `day<N>.txt` input for each day
+	#[arg(long, env = INPUTS_DIR_ENV, default_value = DEFAULT_INPUTS_DIR)]
+	inputs_dir: PathBuf,
+}
+
+impl InputArgs {
+	fn source(&self) -> InputSource {
+		match &self.input {
+			Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+			Some(path) => InputSource::File(path.clone()),
+			None => InputSource::Dir(self.inputs_dir.clone()),
+		}
+	}
+
+	/// Reads the input for `day`.
+	fn read(&self, day: &Day) -> Result<String, String> {
+		self.source()
+			.read(day.number)
+			.map_err(|err| err.to_string())
+	}
+
+	/// Renders a parse error of `day`'s input, pointing at where the input was read from.
+	fn diagnostic(&self, day: &Day, err: ParseError) -> String {
+		err.diagnostic(&self.source().location(day.number))
+	}
+}
+
+/// The day 10 device, defaulting to the puzzle's.
+#[derive(Args)]
+#[command(next_help_heading = "Day 10 device")]
+struct CrtArgs {
+	/// Pixels per row of the CRT
+	#[arg(long, default_value_t = CrtConfig::default().width)]
+	width: usize,
+
+	/// Rows on the CRT
+	#[arg(long, default_value_t = CrtConfig::default().height)]
+	height: usize,
+
+	/// Pixels lit by the sprite
+	#[arg(long, default_value_t = CrtConfig::default().sprite_width)]
+	sprite_width: usize,
+
+	/// Cycle the signal strength is first sampled during
+	#[arg(long, default_value_t = CrtConfig::default().first_sample)]
+	first_sample: usize,
+
+	/// Cycles between signal strength samples
+	#[arg(long, default_value_t = CrtConfig::default().sample_every)]
+	sample_every: usize,
+
+	/// Last cycle the signal strength can be sampled during
+	#[arg(long, default_value_t = CrtConfig::default().last_sample)]
+	last_sample: usize,
+}
+
+impl CrtArgs {
+	fn config(&self) -> CrtConfig {
+		CrtConfig {
+			width: self.width,
+			height: self.height,
+			sprite_width: self.sprite_width,
+			first_sample: self.first_sample,
+			sample_every: self.sample_every,
+			last_sample: self.last_sample,
+		}
+	}
+}
+
+fn main() -> ExitCode {
+	let cli = Cli::parse();
+
+	let result = match cli.command {
+		Command::Run(args) => run::run(args),
+		Command::Bench(args) => bench::bench(args),
+		Command::Verify(args) => verify::verify(args),
+		Command::Fs(command) => fs::fs(command),
+		Command::Debug(args) => debug::debug(args),
+	};
+
+	match result {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(message) => {
+			eprintln!("error: {message}");
+			ExitCode::FAILURE
+		}
+	}
+}
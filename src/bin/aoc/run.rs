@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use aoc2022::{
+	day10::Day10,
+	runner::{self, DayResult, Part, PartResult},
+	Solution,
+};
+use clap::{Args, ValueEnum};
+
+use crate::{CrtArgs, DayArgs, InputArgs};
+
+#[derive(Args)]
+pub struct RunArgs {
+	#[command(flatten)]
+	days: DayArgs,
+
+	/// Only run a single part
+	#[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=2))]
+	part: Option<u8>,
+
+	/// Output format
+	#[arg(long, value_enum, default_value_t = Format::Table)]
+	format: Format,
+
+	/// Show the grid alongside answers read from one, like the day 10 letters
+	#[arg(long)]
+	grid: bool,
+
+	#[command(flatten)]
+	inputs: InputArgs,
+
+	#[command(flatten)]
+	crt: CrtArgs,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+	/// Human readable table
+	Table,
+	/// Machine readable JSON, an array with an entry for each day
+	Json,
+}
+
+pub fn run(args: RunArgs) -> Result<(), String> {
+	let part = args.part.map(|part| match part {
+		1 => Part::One,
+		_ => Part::Two,
+	});
+
+	let mut results = Vec::new();
+	for day in args.days.days()? {
+		let input = args.inputs.read(day)?;
+
+		let result = if day.number == Day10::DAY {
+			runner::run_day10(&input, part, args.crt.config())
+		} else {
+			day.run(&input, part)
+		};
+		let mut result = result.map_err(|err| args.inputs.diagnostic(day, err))?;
+		if args.grid {
+			result.parts.iter_mut().for_each(PartResult::show_grid);
+		}
+
+		results.push(result);
+	}
+
+	match args.format {
+		Format::Table => print_table(&results),
+		Format::Json => println!(
+			"{}",
+			serde_json::to_string_pretty(&results).map_err(|err| err.to_string())?
+		),
+	}
+
+	Ok(())
+}
+
+fn print_table(results: &[DayResult]) {
+	let answer_width = results
+		.iter()
+		.flat_map(|result| &result.parts)
+		.flat_map(|part| {
+			answer_lines(part)
+				.iter()
+				.map(String::len)
+				.collect::<Vec<_>>()
+		})
+		.chain(["Answer".len()])
+		.max()
+		.unwrap_or_default();
+
+	let ms = |duration: Duration| duration.as_micros() as f32 / 1000f32;
+
+	println!(
+		"Day  Parse (ms)  Part  {:answer_width$}  Time (ms)",
+		"Answer"
+	);
+
+	for result in results {
+		for (idx, part) in result.parts.iter().enumerate() {
+			let answer = answer_lines(part);
+			let mut lines = answer.iter();
+
+			// Parsing is shared by both parts, so it's only shown once per day
+			let (day, parse) = if idx == 0 {
+				(
+					result.day.to_string(),
+					format!("{:.3}", ms(result.parse_elapsed)),
+				)
+			} else {
+				Default::default()
+			};
+
+			println!(
+				"{day:>3}  {parse:>10}  {:>4}  {:answer_width$}  {:>9.3}",
+				part.part,
+				lines.next().map(String::as_str).unwrap_or_default(),
+				ms(part.elapsed),
+			);
+
+			// Multi-line answers (like the day 10 CRT) continue underneath the answer column
+			for line in lines {
+				println!("{:23}{line}", "");
+			}
+		}
+	}
+}
+
+/// The answer, then the grid it was read from if that was asked for.
+fn answer_lines(part: &PartResult) -> Vec<String> {
+	part.answer
+		.to_string()
+		.lines()
+		.map(str::to_owned)
+		.chain(part.grid.iter().flatten().cloned())
+		.collect()
+}
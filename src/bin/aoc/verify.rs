@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use aoc2022::answers::{Answers, Outcome, DEFAULT_ANSWERS_PATH};
+use clap::Args;
+
+use crate::{DayArgs, InputArgs};
+
+#[derive(Args)]
+pub struct VerifyArgs {
+	#[command(flatten)]
+	days: DayArgs,
+
+	/// File containing the expected answers
+	#[arg(long, default_value = DEFAULT_ANSWERS_PATH)]
+	answers: PathBuf,
+
+	/// Record the current answers as the expected answers instead of verifying them
+	#[arg(long)]
+	record: bool,
+
+	/// Pass answers that have no expected answer, instead of failing them
+	#[arg(long, conflicts_with = "record")]
+	allow_missing: bool,
+
+	#[command(flatten)]
+	inputs: InputArgs,
+}
+
+pub fn verify(args: VerifyArgs) -> Result<(), String> {
+	let path = args.answers.display();
+
+	let mut answers = match std::fs::read_to_string(&args.answers) {
+		Ok(text) => Answers::parse(&text).map_err(|err| format!("{path}: {err}"))?,
+		// Recording can start from scratch
+		Err(_) if args.record => Answers::default(),
+		Err(err) => return Err(format!("{path}: {err}")),
+	};
+
+	let mut mismatches = 0usize;
+	let mut missing = 0usize;
+
+	println!("Day  Part  Result");
+
+	for day in args.days.days()? {
+		let input = args.inputs.read(day)?;
+
+		let result = day
+			.run(&input, None)
+			.map_err(|err| args.inputs.diagnostic(day, err))?;
+
+		if args.record {
+			answers.record(&result);
+			continue;
+		}
+
+		for part in &result.parts {
+			let outcome = match answers.check(day.number, part) {
+				Outcome::Match => "ok".to_owned(),
+				Outcome::Missing if args.allow_missing => "no expected answer".to_owned(),
+				Outcome::Missing => {
+					missing += 1;
+					"MISSING expected answer".to_owned()
+				}
+				Outcome::Mismatch { expected } => {
+					mismatches += 1;
+					format!(
+						"MISMATCH\n  expected: {expected}\n  actual:   {}",
+						part.answer
+					)
+				}
+			};
+
+			println!("{:>3}  {:>4}  {outcome}", day.number, part.part);
+		}
+	}
+
+	if args.record {
+		let text = answers.to_toml()?;
+		std::fs::write(&args.answers, text).map_err(|err| format!("{path}: {err}"))?;
+		println!("Recorded answers in {path}");
+	}
+
+	let failures = [
+		match mismatches {
+			0 => None,
+			1 => Some("1 answer does not match".to_owned()),
+			count => Some(format!("{count} answers do not match")),
+		},
+		match missing {
+			0 => None,
+			1 => Some(format!("1 answer is missing from {path}")),
+			count => Some(format!("{count} answers are missing from {path}")),
+		},
+	];
+
+	match failures.into_iter().flatten().collect::<Vec<_>>() {
+		failures if failures.is_empty() => Ok(()),
+		failures => Err(failures.join(", ")),
+	}
+}
@@ -0,0 +1,91 @@
+use crate::{ParseError, Solution};
+
+pub type Calories = u32;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+	const DAY: u8 = 1;
+
+	type Parsed<'input> = Vec<Calories>;
+	type Part1 = Result<Calories, &'static str>;
+	type Part2 = Result<Calories, &'static str>;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		parse_elves(input)
+	}
+
+	fn part1(elves: &Self::Parsed<'_>) -> Self::Part1 {
+		elves.iter().max().copied().ok_or("No elves listed")
+	}
+
+	fn part2(elves: &Self::Parsed<'_>) -> Self::Part2 {
+		if elves.is_empty() {
+			return Err("No elves listed");
+		}
+
+		let mut elves = elves.clone();
+		// Sort in reverse
+		elves.sort_unstable_by(|a, b| b.cmp(a));
+		// Fetch the top 3 elves and return the sum of their calories
+		Ok(elves.iter().take(3).copied().sum())
+	}
+}
+
+fn parse_elves(input: &str) -> Result<Vec<Calories>, ParseError> {
+	input
+		.lines()
+		.enumerate()
+		.try_fold(vec![], |mut elves, (idx, line)| {
+			let elf = if line.is_empty() {
+				Calories::default()
+			} else {
+				let calories = elves.pop().unwrap_or_default();
+				calories
+					+ line.parse::<Calories>().map_err(|_| {
+						ParseError::line(Day1::DAY, idx + 1, line, "Expected calory count")
+					})?
+			};
+
+			elves.push(elf);
+
+			Ok(elves)
+		})
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day1::Day1, Solution};
+
+	const TEST_INPUT: &str = r#"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day1::part1(&Day1::parse(TEST_INPUT).unwrap()), Ok(24000));
+	}
+
+	#[test]
+	fn test_empty() {
+		let elves = Day1::parse("").unwrap();
+		assert_eq!(Day1::part1(&elves), Err("No elves listed"));
+		assert_eq!(Day1::part2(&elves), Err("No elves listed"));
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day1::part2(&Day1::parse(TEST_INPUT).unwrap()), Ok(45000));
+	}
+}
@@ -0,0 +1,766 @@
+use std::{fmt, mem};
+
+use crate::{day10::ocr::Screen, grid::Grid, point::Point2, ParseError, Solution};
+
+pub mod assembler;
+pub mod debugger;
+pub mod ocr;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+	const DAY: u8 = 10;
+
+	type Parsed<'input> = Vec<Instruction>;
+	type Part1 = Result<isize, Overflow>;
+	type Part2 = Result<Screen, Overflow>;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		assembler::assemble(input)
+	}
+
+	fn part1(program: &Self::Parsed<'_>) -> Self::Part1 {
+		Self::signal_strength(program, CrtConfig::default())
+	}
+
+	fn part2(program: &Self::Parsed<'_>) -> Self::Part2 {
+		Self::screen(program, CrtConfig::default())
+	}
+}
+
+impl Day10 {
+	/// Part 1 on a device sampling the signal strength on `config`'s schedule.
+	pub fn signal_strength(program: &[Instruction], config: CrtConfig) -> Result<isize, Overflow> {
+		let mut strength = SignalStrength::new(config);
+		Cpu::new(program).run(&mut [&mut strength])?;
+		strength.sum
+	}
+
+	/// Part 2 on a device with `config`'s screen.
+	pub fn screen(program: &[Instruction], config: CrtConfig) -> Result<Screen, Overflow> {
+		let mut crt = Crt::new(config);
+		Cpu::new(program).run(&mut [&mut crt])?;
+		Ok(Screen { rows: crt.rows() })
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+	Noop,
+	Addx(isize),
+	Subx(isize),
+	Mulx(isize),
+	/// Jump by an offset from this instruction
+	Jmp(isize),
+	/// Jump by an offset from this instruction if X isn't zero
+	Jnz(isize),
+}
+
+/// The kind of operand an instruction takes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+	None,
+	Value,
+	/// An offset from the instruction, which the assembler can also take as a label
+	Target,
+}
+
+/// An entry in the instruction table.
+#[derive(Clone, Copy, Debug)]
+pub struct Opcode {
+	pub mnemonic: &'static str,
+	/// Cycles taken to execute the instruction
+	pub cycles: usize,
+	pub operand: Operand,
+	/// Builds the instruction from its operand, which is 0 if it takes none
+	pub build: fn(isize) -> Instruction,
+	/// The registers once the instruction finishes, from the registers and operand before it,
+	/// or `None` if X overflows
+	pub execute: fn(Registers, isize) -> Option<Registers>,
+}
+
+/// The registers an instruction can change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Registers {
+	pub x: isize,
+	/// Index of the instruction being executed
+	pub pc: usize,
+}
+
+impl Registers {
+	/// Moves on to the next instruction with X set to `x`.
+	fn advance(self, x: isize) -> Self {
+		Self { x, pc: self.pc + 1 }
+	}
+
+	/// Jumps by `offset` from the current instruction. Jumping outside the program ends it.
+	fn jump(self, offset: isize) -> Self {
+		Self {
+			pc: self.pc.checked_add_signed(offset).unwrap_or(usize::MAX),
+			..self
+		}
+	}
+}
+
+/// Every instruction the CPU knows.
+pub const OPCODES: &[Opcode] = &[
+	Opcode {
+		mnemonic: "noop",
+		cycles: 1,
+		operand: Operand::None,
+		build: |_| Instruction::Noop,
+		execute: |registers, _| Some(registers.advance(registers.x)),
+	},
+	Opcode {
+		mnemonic: "addx",
+		cycles: 2,
+		operand: Operand::Value,
+		build: Instruction::Addx,
+		execute: |registers, value| Some(registers.advance(registers.x.checked_add(value)?)),
+	},
+	Opcode {
+		mnemonic: "subx",
+		cycles: 2,
+		operand: Operand::Value,
+		build: Instruction::Subx,
+		execute: |registers, value| Some(registers.advance(registers.x.checked_sub(value)?)),
+	},
+	Opcode {
+		mnemonic: "mulx",
+		cycles: 4,
+		operand: Operand::Value,
+		build: Instruction::Mulx,
+		execute: |registers, value| Some(registers.advance(registers.x.checked_mul(value)?)),
+	},
+	Opcode {
+		mnemonic: "jmp",
+		cycles: 1,
+		operand: Operand::Target,
+		build: Instruction::Jmp,
+		execute: |registers, offset| Some(registers.jump(offset)),
+	},
+	Opcode {
+		mnemonic: "jnz",
+		cycles: 2,
+		operand: Operand::Target,
+		build: Instruction::Jnz,
+		execute: |registers, offset| match registers.x {
+			0 => Some(registers.advance(registers.x)),
+			_ => Some(registers.jump(offset)),
+		},
+	},
+];
+
+impl Instruction {
+	/// The instruction's entry in [`OPCODES`], the one building the same variant.
+	pub fn opcode(self) -> &'static Opcode {
+		let variant = mem::discriminant(&self);
+		OPCODES
+			.iter()
+			.find(|opcode| mem::discriminant(&(opcode.build)(0)) == variant)
+			.expect("Every instruction should have an opcode")
+	}
+
+	pub fn operand(self) -> Option<isize> {
+		match self {
+			Self::Noop => None,
+			Self::Addx(value)
+			| Self::Subx(value)
+			| Self::Mulx(value)
+			| Self::Jmp(value)
+			| Self::Jnz(value) => Some(value),
+		}
+	}
+
+	/// Cycles taken to execute the instruction.
+	pub fn cycles(self) -> usize {
+		self.opcode().cycles
+	}
+}
+
+impl fmt::Display for Instruction {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.opcode().mnemonic)?;
+		if let Some(operand) = self.operand() {
+			write!(f, " {operand}")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// The state of the CPU during a cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct State {
+	/// Starting from 1
+	pub cycle: usize,
+	/// The X register, before the instruction finishing this cycle is applied
+	pub x: isize,
+	/// Index of the instruction being executed
+	pub pc: usize,
+}
+
+/// Arithmetic that overflowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+	/// The instruction finishing during the cycle overflowed X, which ends the program
+	X {
+		cycle: usize,
+		instruction: Instruction,
+	},
+	/// Sampling the signal strength during the cycle overflowed it, or its sum
+	SignalStrength { cycle: usize },
+}
+
+impl fmt::Display for Overflow {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::X { cycle, instruction } => {
+				write!(f, "`{instruction}` overflows X during cycle {cycle}")
+			}
+			Self::SignalStrength { cycle } => {
+				write!(f, "The signal strength overflows during cycle {cycle}")
+			}
+		}
+	}
+}
+
+impl std::error::Error for Overflow {}
+
+/// Executes a program one cycle at a time, yielding the [`State`] during each cycle.
+#[derive(Clone, Debug)]
+pub struct Cpu<'program> {
+	program: &'program [Instruction],
+	registers: Registers,
+	cycle: usize,
+	/// Cycles left before the current instruction finishes
+	remaining: usize,
+	overflow: Option<Overflow>,
+}
+
+impl<'program> Cpu<'program> {
+	pub fn new(program: &'program [Instruction]) -> Self {
+		Self {
+			program,
+			registers: Registers { x: 1, pc: 0 },
+			cycle: 0,
+			remaining: 0,
+			overflow: None,
+		}
+	}
+
+	pub fn x(&self) -> isize {
+		self.registers.x
+	}
+
+	/// What ended the program, if it overflowed.
+	pub fn overflow(&self) -> Option<Overflow> {
+		self.overflow
+	}
+
+	/// Runs the program, showing every cycle to each of `observers` in turn, until it ends or every
+	/// observer is done. Fails if X overflowed, though the observers have still seen every cycle
+	/// until then.
+	pub fn run(&mut self, observers: &mut [&mut dyn Observer]) -> Result<(), Overflow> {
+		while !observers.iter().all(|observer| observer.is_done()) {
+			let Some(state) = self.next() else {
+				break;
+			};
+
+			for observer in observers.iter_mut() {
+				observer.observe(&state);
+			}
+		}
+
+		self.overflow.map_or(Ok(()), Err)
+	}
+}
+
+impl Iterator for Cpu<'_> {
+	type Item = State;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.overflow.is_some() {
+			return None;
+		}
+
+		let instruction = *self.program.get(self.registers.pc)?;
+		if self.remaining == 0 {
+			self.remaining = instruction.cycles();
+		}
+
+		self.cycle += 1;
+		let state = State {
+			cycle: self.cycle,
+			x: self.registers.x,
+			pc: self.registers.pc,
+		};
+
+		self.remaining -= 1;
+		if self.remaining == 0 {
+			let operand = instruction.operand().unwrap_or_default();
+			match (instruction.opcode().execute)(self.registers, operand) {
+				Some(registers) => self.registers = registers,
+				None => {
+					self.overflow = Some(Overflow::X {
+						cycle: self.cycle,
+						instruction,
+					})
+				}
+			}
+		}
+
+		Some(state)
+	}
+}
+
+/// Something watching the CPU cycle by cycle.
+pub trait Observer {
+	fn observe(&mut self, state: &State);
+
+	/// Whether later cycles no longer matter, so programs that never end can be stopped.
+	fn is_done(&self) -> bool {
+		false
+	}
+}
+
+impl<F: FnMut(&State)> Observer for F {
+	fn observe(&mut self, state: &State) {
+		self(state)
+	}
+}
+
+/// The screen and signal sampling schedule of a device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrtConfig {
+	/// Pixels per row
+	pub width: usize,
+	/// Rows on the screen
+	pub height: usize,
+	/// Pixels lit by the sprite, from X - (sprite_width - 1) / 2
+	pub sprite_width: usize,
+	/// Cycle the signal strength is first sampled during
+	pub first_sample: usize,
+	/// Cycles between samples
+	pub sample_every: usize,
+	/// No samples are taken after this cycle
+	pub last_sample: usize,
+}
+
+impl Default for CrtConfig {
+	/// The puzzle's device.
+	fn default() -> Self {
+		Self {
+			width: 40,
+			height: 6,
+			sprite_width: 3,
+			first_sample: 20,
+			sample_every: 40,
+			last_sample: 220,
+		}
+	}
+}
+
+impl CrtConfig {
+	pub fn is_sampled(&self, cycle: usize) -> bool {
+		(self.first_sample..=self.last_sample).contains(&cycle)
+			&& (cycle - self.first_sample).is_multiple_of(self.sample_every.max(1))
+	}
+
+	/// Whether the sprite lights `column` when it is at `x`.
+	pub fn is_lit(&self, column: usize, x: isize) -> bool {
+		let left = x - (self.sprite_width as isize - 1) / 2;
+		(left..left + self.sprite_width as isize).contains(&(column as isize))
+	}
+}
+
+/// Sums the signal strength, the cycle times X, during each sampled cycle.
+#[derive(Clone, Debug)]
+pub struct SignalStrength {
+	pub config: CrtConfig,
+	pub sum: Result<isize, Overflow>,
+	cycle: usize,
+}
+
+impl Default for SignalStrength {
+	fn default() -> Self {
+		Self::new(CrtConfig::default())
+	}
+}
+
+impl SignalStrength {
+	pub fn new(config: CrtConfig) -> Self {
+		Self {
+			config,
+			sum: Ok(0),
+			cycle: 0,
+		}
+	}
+}
+
+impl Observer for SignalStrength {
+	fn observe(&mut self, state: &State) {
+		self.cycle = state.cycle;
+		if let (Ok(sum), true) = (self.sum, self.config.is_sampled(state.cycle)) {
+			self.sum = isize::try_from(state.cycle)
+				.ok()
+				.and_then(|cycle| cycle.checked_mul(state.x))
+				.and_then(|strength| sum.checked_add(strength))
+				.ok_or(Overflow::SignalStrength { cycle: state.cycle });
+		}
+	}
+
+	fn is_done(&self) -> bool {
+		self.sum.is_err() || self.cycle >= self.config.last_sample
+	}
+}
+
+/// A screen drawing one pixel per cycle, row by row, lit when the sprite centred on X overlaps
+/// it.
+#[derive(Clone, Debug)]
+pub struct Crt {
+	config: CrtConfig,
+	pixels: Grid<bool>,
+	cycle: usize,
+}
+
+impl Default for Crt {
+	fn default() -> Self {
+		Self::new(CrtConfig::default())
+	}
+}
+
+impl Crt {
+	pub fn new(config: CrtConfig) -> Self {
+		Self {
+			config,
+			pixels: Grid::new(config.width, config.height, false),
+			cycle: 0,
+		}
+	}
+
+	pub fn pixels(&self) -> &Grid<bool> {
+		&self.pixels
+	}
+
+	/// Rows of `#` for lit and `.` for dark pixels.
+	pub fn rows(&self) -> Vec<String> {
+		self.pixels.render(|lit| if *lit { '#' } else { '.' })
+	}
+}
+
+impl Observer for Crt {
+	fn observe(&mut self, state: &State) {
+		self.cycle = state.cycle;
+		let index = state.cycle - 1;
+		let column = index % self.config.width.max(1);
+		let position = Point2::new(column as isize, (index / self.config.width.max(1)) as isize);
+
+		if let Some(pixel) = self.pixels.get_mut(position) {
+			*pixel = self.config.is_lit(column, state.x);
+		}
+	}
+
+	fn is_done(&self) -> bool {
+		self.cycle >= self.pixels.as_slice().len()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{
+		day10::{
+			Cpu, Crt, CrtConfig, Day10, Instruction, Overflow, SignalStrength, State, OPCODES,
+		},
+		Solution,
+	};
+
+	const TEST_INPUT: &str = r#"addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day10::part1(&Day10::parse(TEST_INPUT).unwrap()), Ok(13140));
+	}
+
+	#[test]
+	fn test_opcodes() {
+		for opcode in OPCODES {
+			let instruction = (opcode.build)(3);
+			assert_eq!(instruction.opcode().mnemonic, opcode.mnemonic);
+		}
+	}
+
+	#[test]
+	fn test_cpu() {
+		let program = Day10::parse("noop\naddx 3\naddx -5").unwrap();
+		let states = Cpu::new(&program)
+			.map(|state| (state.cycle, state.x, state.pc))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			states,
+			[(1, 1, 0), (2, 1, 1), (3, 1, 1), (4, 4, 2), (5, 4, 2)]
+		);
+
+		let mut cpu = Cpu::new(&program);
+		let mut cycles = 0;
+		cpu.run(&mut [&mut |_: &State| cycles += 1]).unwrap();
+		assert_eq!((cycles, cpu.x()), (5, -1));
+	}
+
+	#[test]
+	fn test_jumps() {
+		// Counts X down from 3 to 0, then jumps out of the program
+		let program = Day10::parse(
+			"addx 2
+loop: subx 1
+jnz loop
+jmp -10",
+		)
+		.unwrap();
+
+		let states = Cpu::new(&program)
+			.map(|state| (state.x, state.pc))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			states,
+			[
+				(1, 0),
+				(1, 0),
+				(3, 1),
+				(3, 1),
+				(2, 2),
+				(2, 2),
+				(2, 1),
+				(2, 1),
+				(1, 2),
+				(1, 2),
+				(1, 1),
+				(1, 1),
+				(0, 2),
+				(0, 2),
+				(0, 3)
+			]
+		);
+
+		// Both parts stop once the screen is drawn, even if the program never ends
+		let program = Day10::parse("start: addx 1\njmp start").unwrap();
+		assert_eq!(
+			Day10::part2(&program).unwrap().rows[0],
+			"####...................................."
+		);
+		assert_eq!(Day10::part1(&program), Ok(38640));
+	}
+
+	#[test]
+	fn test_config() {
+		let program = Day10::parse(TEST_INPUT).unwrap();
+		let config = CrtConfig {
+			width: 20,
+			height: 2,
+			sprite_width: 1,
+			first_sample: 10,
+			sample_every: 10,
+			last_sample: 30,
+		};
+
+		let mut strength = SignalStrength::new(config);
+		let mut crt = Crt::new(config);
+		Cpu::new(&program)
+			.run(&mut [&mut strength, &mut crt])
+			.unwrap();
+
+		assert_eq!(strength.sum, Ok(10 * 8 + 20 * 21 + 30 * 28));
+		assert_eq!(crt.rows(), [".#...#..#...#....#..", "...................."]);
+
+		assert_eq!(Day10::signal_strength(&program, config), strength.sum);
+		assert_eq!(Day10::screen(&program, config).unwrap().rows, crt.rows());
+	}
+
+	#[test]
+	fn test_overflow() {
+		let program =
+			Day10::parse("addx 1000000\nmulx 1000000\nmulx 1000000\nmulx 1000000").unwrap();
+		let overflow = Overflow::X {
+			cycle: 14,
+			instruction: Instruction::Mulx(1000000),
+		};
+		assert_eq!(Day10::part1(&program), Err(overflow));
+		assert_eq!(Day10::part2(&program), Err(overflow));
+
+		let mut cpu = Cpu::new(&program);
+		assert_eq!(cpu.by_ref().count(), 14);
+		assert_eq!(
+			(cpu.overflow(), cpu.x()),
+			(Some(overflow), 1000001000000000000)
+		);
+
+		let program = Day10::parse("addx 9223372036854775806\nstart: jmp start").unwrap();
+		assert_eq!(
+			Day10::part1(&program),
+			Err(Overflow::SignalStrength { cycle: 20 })
+		);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(
+			Day10::part2(&Day10::parse(TEST_INPUT).unwrap())
+				.unwrap()
+				.rows,
+			r#"##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."#
+				.lines()
+				.collect::<Vec<_>>()
+		);
+	}
+}
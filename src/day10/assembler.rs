@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use crate::{
+	day10::{Day10, Instruction, Operand, OPCODES},
+	ParseError, Solution,
+};
+
+/// Assembles a program, one instruction per line.
+///
+/// `#` starts a comment, and a word ending in `:` at the start of a line labels the next
+/// instruction. Jumps take either a label or an offset from the jump itself.
+pub fn assemble(source: &str) -> Result<Vec<Instruction>, ParseError> {
+	let lines = source
+		.lines()
+		.enumerate()
+		.map(|(idx, line)| {
+			let code = line.split('#').next().unwrap_or_default();
+			(idx + 1, line, code.split_whitespace().collect::<Vec<_>>())
+		})
+		.collect::<Vec<_>>();
+
+	// Labels are collected first so jumps can go forwards
+	let mut labels = HashMap::new();
+	let mut count = 0;
+	for (number, line, words) in &lines {
+		let mut words = words.as_slice();
+		while let Some(label) = words.first().and_then(|word| word.strip_suffix(':')) {
+			if labels.insert(label, count).is_some() {
+				return Err(ParseError::token(
+					Day10::DAY,
+					*number,
+					line,
+					words[0],
+					format!("Label `{label}` is defined twice"),
+				));
+			}
+			words = &words[1..];
+		}
+
+		if !words.is_empty() {
+			count += 1;
+		}
+	}
+
+	let mut program = Vec::with_capacity(count);
+	for (number, line, words) in &lines {
+		let words = words
+			.iter()
+			.skip_while(|word| word.ends_with(':'))
+			.collect::<Vec<_>>();
+		let Some(mnemonic) = words.first() else {
+			continue;
+		};
+
+		let error = |token: &str, message: String| {
+			ParseError::token(Day10::DAY, *number, line, token, message)
+		};
+
+		let opcode = OPCODES
+			.iter()
+			.find(|opcode| opcode.mnemonic == **mnemonic)
+			.ok_or_else(|| {
+				let known = OPCODES
+					.iter()
+					.map(|opcode| opcode.mnemonic)
+					.collect::<Vec<_>>();
+				error(
+					mnemonic,
+					format!(
+						"Unknown mnemonic `{mnemonic}`, expected one of {}",
+						known.join(", ")
+					),
+				)
+			})?;
+
+		let operand = match (opcode.operand, words.get(1)) {
+			(Operand::None, _) => 0,
+			(_, None) => {
+				return Err(ParseError::new(
+					Day10::DAY,
+					*number,
+					line,
+					line.len(),
+					0,
+					"Expected value",
+				))
+			}
+			(Operand::Value, Some(value)) => value
+				.parse()
+				.map_err(|_| error(value, "Invalid value".to_owned()))?,
+			(Operand::Target, Some(target)) => match target.parse::<isize>() {
+				Ok(offset) => offset,
+				Err(_) => {
+					let index = labels
+						.get(**target)
+						.ok_or_else(|| error(target, format!("Unknown label `{target}`")))?;
+					*index as isize - program.len() as isize
+				}
+			},
+		};
+
+		let expected = if opcode.operand == Operand::None {
+			1
+		} else {
+			2
+		};
+		if let Some(extra) = words.get(expected) {
+			return Err(error(extra, "Unexpected trailing input".to_owned()));
+		}
+
+		program.push((opcode.build)(operand));
+	}
+
+	Ok(program)
+}
+
+#[cfg(test)]
+mod test {
+	use crate::day10::{assembler::assemble, Instruction};
+
+	#[test]
+	fn test_assemble() {
+		let program = assemble(
+			"start: addx 3 # X is 4
+mulx 2
+
+loop:
+	subx 1
+	jnz loop
+	jmp end
+	noop
+end: jmp -6",
+		)
+		.unwrap();
+
+		assert_eq!(
+			program,
+			[
+				Instruction::Addx(3),
+				Instruction::Mulx(2),
+				Instruction::Subx(1),
+				Instruction::Jnz(-1),
+				Instruction::Jmp(2),
+				Instruction::Noop,
+				Instruction::Jmp(-6),
+			]
+		);
+	}
+
+	#[test]
+	fn test_errors() {
+		let err = assemble("noop\n\naddx 1\nmovx 2").unwrap_err();
+		assert_eq!((err.line, err.column, err.text.as_str()), (4, 1, "movx"));
+		assert!(err.message.starts_with("Unknown mnemonic `movx`"));
+
+		let err = assemble("jmp nowhere").unwrap_err();
+		assert_eq!(err.message, "Unknown label `nowhere`");
+
+		let err = assemble("a: noop\na: noop").unwrap_err();
+		assert_eq!(
+			(err.line, err.message.as_str()),
+			(2, "Label `a` is defined twice")
+		);
+
+		let err = assemble("noop 1").unwrap_err();
+		assert_eq!(err.message, "Unexpected trailing input");
+
+		let err = assemble("addx").unwrap_err();
+		assert_eq!(err.message, "Expected value");
+	}
+}
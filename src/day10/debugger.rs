@@ -0,0 +1,395 @@
+use std::{
+	fmt::{self, Write},
+	str::FromStr,
+};
+
+use crate::day10::{Cpu, Crt, CrtConfig, Instruction, Observer, State};
+
+/// Most cycles a single [`Command::Step`] or [`Command::Continue`] runs, so programs that never
+/// end still stop.
+pub const CYCLE_LIMIT: usize = 100_000;
+
+/// Where [`Command::Continue`] stops.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Breakpoint {
+	/// During this cycle
+	Cycle(usize),
+	/// During the first cycle X compares to the value this way, after a cycle it didn't
+	X(Comparison, isize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+	Equal,
+	NotEqual,
+	Less,
+	LessOrEqual,
+	Greater,
+	GreaterOrEqual,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+	/// Run this many cycles, at most [`CYCLE_LIMIT`]
+	Step(usize),
+	/// Run until a breakpoint, the end of the program, the screen being drawn or
+	/// [`CYCLE_LIMIT`] cycles
+	Continue,
+	Break(Breakpoint),
+	/// Delete one breakpoint by its number from 1, or all of them
+	Delete(Option<usize>),
+	/// List the breakpoints
+	Breakpoints,
+	/// Show the current state
+	Print,
+	Quit,
+}
+
+impl Comparison {
+	pub fn holds(self, left: isize, right: isize) -> bool {
+		match self {
+			Self::Equal => left == right,
+			Self::NotEqual => left != right,
+			Self::Less => left < right,
+			Self::LessOrEqual => left <= right,
+			Self::Greater => left > right,
+			Self::GreaterOrEqual => left >= right,
+		}
+	}
+
+	fn symbol(self) -> &'static str {
+		match self {
+			Self::Equal => "==",
+			Self::NotEqual => "!=",
+			Self::Less => "<",
+			Self::LessOrEqual => "<=",
+			Self::Greater => ">",
+			Self::GreaterOrEqual => ">=",
+		}
+	}
+}
+
+impl FromStr for Comparison {
+	type Err = String;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		[
+			Self::Equal,
+			Self::NotEqual,
+			Self::Less,
+			Self::LessOrEqual,
+			Self::Greater,
+			Self::GreaterOrEqual,
+		]
+		.into_iter()
+		.find(|comparison| comparison.symbol() == text)
+		.ok_or(format!("Unknown comparison `{text}`"))
+	}
+}
+
+impl FromStr for Command {
+	type Err = String;
+
+	/// Parses commands like `step 5`, `break cycle 20`, `break x >= 10` or `delete 1`.
+	fn from_str(line: &str) -> Result<Self, Self::Err> {
+		let words = line.split_whitespace().collect::<Vec<_>>();
+		match words[..] {
+			["step" | "s"] => Ok(Self::Step(1)),
+			["step" | "s", count] => number(count).map(Self::Step),
+			["continue" | "c"] => Ok(Self::Continue),
+			["break" | "b", "cycle", cycle] => {
+				number(cycle).map(|cycle| Self::Break(Breakpoint::Cycle(cycle)))
+			}
+			["break" | "b", "x" | "X", comparison, value] => Ok(Self::Break(Breakpoint::X(
+				comparison.parse()?,
+				number(value)?,
+			))),
+			["delete" | "d"] => Ok(Self::Delete(None)),
+			["delete" | "d", index] => number(index).map(|index| Self::Delete(Some(index))),
+			["breakpoints" | "info"] => Ok(Self::Breakpoints),
+			["print" | "p"] => Ok(Self::Print),
+			["quit" | "q"] => Ok(Self::Quit),
+			["step" | "s" | "break" | "b" | "delete" | "d", ..] => {
+				Err(format!("Unexpected arguments to `{}`", words[0]))
+			}
+			_ => Err(format!(
+				"Unknown command `{line}`, expected step, continue, break, delete, breakpoints, print or quit"
+			)),
+		}
+	}
+}
+
+fn number<T: FromStr>(text: &str) -> Result<T, String> {
+	text.parse()
+		.map_err(|_| format!("Expected a number, found `{text}`"))
+}
+
+impl fmt::Display for Breakpoint {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Cycle(cycle) => write!(f, "cycle {cycle}"),
+			Self::X(comparison, value) => write!(f, "x {} {value}", comparison.symbol()),
+		}
+	}
+}
+
+/// Steps a day 10 program through the CPU, drawing the CRT as it goes.
+pub struct Debugger<'program> {
+	program: &'program [Instruction],
+	cpu: Cpu<'program>,
+	crt: Crt,
+	config: CrtConfig,
+	/// The most recent cycle, `None` before the first
+	state: Option<State>,
+	/// X during the cycle before the most recent one
+	previous_x: Option<isize>,
+	breakpoints: Vec<Breakpoint>,
+}
+
+impl<'program> Debugger<'program> {
+	pub fn new(program: &'program [Instruction], config: CrtConfig) -> Self {
+		Self {
+			program,
+			cpu: Cpu::new(program),
+			crt: Crt::new(config),
+			config,
+			state: None,
+			previous_x: None,
+			breakpoints: Vec::new(),
+		}
+	}
+
+	pub fn state(&self) -> Option<State> {
+		self.state
+	}
+
+	pub fn crt(&self) -> &Crt {
+		&self.crt
+	}
+
+	/// Runs `command`, returning what it prints.
+	pub fn execute(&mut self, command: Command) -> String {
+		let mut output = String::new();
+
+		match command {
+			Command::Step(count) => {
+				let mut ran = 0;
+				while ran < count.min(CYCLE_LIMIT) && self.tick() {
+					ran += 1;
+				}
+
+				if ran == CYCLE_LIMIT && count > CYCLE_LIMIT {
+					let _ = writeln!(output, "Stopped after {CYCLE_LIMIT} cycles");
+				}
+				self.print(&mut output);
+			}
+			Command::Continue => {
+				for count in 1..=CYCLE_LIMIT {
+					let drawn = self.crt.is_done();
+					if !self.tick() {
+						break;
+					}
+
+					if let Some(index) = self.hit() {
+						let _ = writeln!(
+							output,
+							"Breakpoint {}: {}",
+							index + 1,
+							self.breakpoints[index]
+						);
+						break;
+					}
+
+					if !drawn && self.crt.is_done() {
+						output.push_str("Stopped once the screen was drawn\n");
+						break;
+					}
+
+					if count == CYCLE_LIMIT {
+						let _ = writeln!(output, "Stopped after {CYCLE_LIMIT} cycles");
+					}
+				}
+				self.print(&mut output);
+			}
+			Command::Break(breakpoint) => {
+				self.breakpoints.push(breakpoint);
+				let _ = writeln!(
+					output,
+					"Breakpoint {}: {breakpoint}",
+					self.breakpoints.len()
+				);
+			}
+			Command::Delete(None) => self.breakpoints.clear(),
+			Command::Delete(Some(index)) => {
+				if (1..=self.breakpoints.len()).contains(&index) {
+					self.breakpoints.remove(index - 1);
+				} else {
+					let _ = writeln!(output, "No breakpoint {index}");
+				}
+			}
+			Command::Breakpoints => {
+				if self.breakpoints.is_empty() {
+					output.push_str("No breakpoints\n");
+				}
+				for (index, breakpoint) in self.breakpoints.iter().enumerate() {
+					let _ = writeln!(output, "{}: {breakpoint}", index + 1);
+				}
+			}
+			Command::Print => self.print(&mut output),
+			Command::Quit => {}
+		}
+
+		output
+	}
+
+	/// Runs a single cycle, returning `false` if the program has already finished.
+	fn tick(&mut self) -> bool {
+		let Some(state) = self.cpu.next() else {
+			return false;
+		};
+
+		self.crt.observe(&state);
+		self.previous_x = self.state.map(|state| state.x);
+		self.state = Some(state);
+		true
+	}
+
+	/// The first breakpoint hit by the most recent cycle.
+	fn hit(&self) -> Option<usize> {
+		let state = self.state?;
+
+		self.breakpoints
+			.iter()
+			.position(|breakpoint| match *breakpoint {
+				Breakpoint::Cycle(cycle) => state.cycle == cycle,
+				Breakpoint::X(comparison, value) => {
+					comparison.holds(state.x, value)
+						&& !self
+							.previous_x
+							.is_some_and(|previous| comparison.holds(previous, value))
+				}
+			})
+	}
+
+	/// Writes the cycle, X, the instruction being executed and the CRT row drawn so far.
+	fn print(&self, output: &mut String) {
+		let Some(state) = self.state else {
+			output.push_str("Not started\n");
+			return;
+		};
+
+		let instruction = self.program[state.pc];
+		let _ = writeln!(
+			output,
+			"cycle {}  X {}  {instruction} (instruction {})",
+			state.cycle,
+			state.x,
+			state.pc + 1
+		);
+
+		let width = self.config.width.max(1);
+		let drawn = (state.cycle - 1) % width + 1;
+		if let Some(row) = self.crt.rows().get((state.cycle - 1) / width) {
+			let _ = writeln!(output, "{}", &row[..drawn]);
+		}
+
+		if let Some(overflow) = self.cpu.overflow() {
+			let _ = writeln!(output, "Ended after {} cycles: {overflow}", state.cycle);
+		} else if self.cpu.clone().next().is_none() {
+			let _ = writeln!(
+				output,
+				"Finished after {} cycles with X {}",
+				state.cycle,
+				self.cpu.x()
+			);
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{
+		day10::{
+			debugger::{Breakpoint, Command, Comparison, Debugger, CYCLE_LIMIT},
+			CrtConfig, Day10,
+		},
+		Solution,
+	};
+
+	#[test]
+	fn test_commands() {
+		assert_eq!("s 3".parse(), Ok(Command::Step(3)));
+		assert_eq!(
+			"break x >= 10".parse(),
+			Ok(Command::Break(Breakpoint::X(
+				Comparison::GreaterOrEqual,
+				10
+			)))
+		);
+		assert_eq!(
+			"break cycle 20".parse(),
+			Ok(Command::Break(Breakpoint::Cycle(20)))
+		);
+		assert!("break x ~ 1".parse::<Command>().is_err());
+		assert!("step -1".parse::<Command>().is_err());
+		assert!("jump".parse::<Command>().is_err());
+	}
+
+	#[test]
+	fn test_endless() {
+		let program = Day10::parse("start: addx 1\njmp start").unwrap();
+		let mut debugger = Debugger::new(&program, CrtConfig::default());
+
+		let output = debugger.execute(Command::Continue);
+		assert!(output.starts_with("Stopped once the screen was drawn\ncycle 240  X 81"));
+
+		let output = debugger.execute(Command::Continue);
+		assert!(output.starts_with(&format!("Stopped after {CYCLE_LIMIT} cycles\n")));
+		assert_eq!(debugger.state().unwrap().cycle, 240 + CYCLE_LIMIT);
+
+		let output = debugger.execute(Command::Step(usize::MAX));
+		assert!(output.starts_with(&format!("Stopped after {CYCLE_LIMIT} cycles\n")));
+		assert_eq!(debugger.state().unwrap().cycle, 240 + 2 * CYCLE_LIMIT);
+
+		let output = debugger.execute(Command::Step(CYCLE_LIMIT));
+		assert!(output.starts_with("cycle "));
+	}
+
+	#[test]
+	fn test_script() {
+		let program = Day10::parse("noop\naddx 3\naddx -5\nnoop").unwrap();
+		let mut debugger = Debugger::new(&program, CrtConfig::default());
+
+		let script = [
+			"break x == 4",
+			"continue",
+			"step",
+			"break cycle 6",
+			"c",
+			"c",
+		];
+		let output = script
+			.iter()
+			.map(|line| debugger.execute(line.parse().unwrap()))
+			.collect::<String>();
+
+		assert_eq!(
+			output,
+			"Breakpoint 1: x == 4
+Breakpoint 1: x == 4
+cycle 4  X 4  addx -5 (instruction 3)
+####
+cycle 5  X 4  addx -5 (instruction 3)
+#####
+Breakpoint 2: cycle 6
+Breakpoint 2: cycle 6
+cycle 6  X -1  noop (instruction 4)
+#####.
+Finished after 6 cycles with X -1
+cycle 6  X -1  noop (instruction 4)
+#####.
+Finished after 6 cycles with X -1
+"
+		);
+	}
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+
+use crate::Answer;
+
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+/// Glyphs are separated by a blank column
+const GLYPH_SPACING: usize = GLYPH_WIDTH + 1;
+
+/// The capital letters the puzzles draw, row by row.
+const GLYPHS: &[(char, [&str; GLYPH_HEIGHT])] = &[
+	('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+	('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+	('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+	('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+	('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+	('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+	('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+	('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+	('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+	('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+	('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+	('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+	('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+	('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+	('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+	('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+	('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Why a screen couldn't be read as letters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unreadable {
+	/// The screen has this many rows instead of the letters' height
+	Height(usize),
+	/// A glyph that isn't a known letter
+	UnknownGlyph {
+		/// Index of the glyph, from 0 at the left
+		index: usize,
+	},
+}
+
+impl fmt::Display for Unreadable {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Height(rows) => write!(
+				f,
+				"Letters are {GLYPH_HEIGHT} rows high, but the screen has {rows}"
+			),
+			Self::UnknownGlyph { index } => write!(
+				f,
+				"Unrecognised letter {} at column {}",
+				index + 1,
+				index * GLYPH_SPACING
+			),
+		}
+	}
+}
+
+impl std::error::Error for Unreadable {}
+
+/// Reads the letters drawn in `rows` of `#` and `.`, which must be 6 rows of 4 pixel wide
+/// letters separated by blank columns.
+pub fn read(rows: &[impl AsRef<str>]) -> Result<String, Unreadable> {
+	if rows.len() != GLYPH_HEIGHT {
+		return Err(Unreadable::Height(rows.len()));
+	}
+
+	let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+
+	(0..width.div_ceil(GLYPH_SPACING))
+		.map(|index| {
+			let start = index * GLYPH_SPACING;
+			let glyph = rows
+				.iter()
+				.map(|row| row.as_ref().get(start..start + GLYPH_WIDTH));
+
+			GLYPHS
+				.iter()
+				.find(|(_, pixels)| glyph.clone().eq(pixels.iter().map(|row| Some(*row))))
+				.map(|(letter, _)| *letter)
+				.ok_or(Unreadable::UnknownGlyph { index })
+		})
+		.collect()
+}
+
+/// A screen of pixels, answered with the letters it shows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Screen {
+	pub rows: Vec<String>,
+}
+
+impl Screen {
+	pub fn read(&self) -> Result<String, Unreadable> {
+		read(&self.rows)
+	}
+}
+
+impl From<Screen> for Answer {
+	/// The letters alongside the raw rows, or just the rows if they aren't all letters.
+	fn from(screen: Screen) -> Self {
+		match screen.read() {
+			Ok(text) => Self::Letters {
+				text,
+				rows: screen.rows,
+			},
+			Err(_) => Self::Grid(screen.rows),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::day10::ocr::{read, Unreadable};
+
+	#[test]
+	fn test_read() {
+		let rows = [
+			"#..#.####.####.####.#..#.#..#..##..####.",
+			"#..#.#....#.......#.#..#.#..#.#..#....#.",
+			"####.###..###....#..#..#.####.#......#..",
+			"#..#.#....#.....#...#..#.#..#.#.....#...",
+			"#..#.#....#....#....#..#.#..#.#..#.#....",
+			"#..#.####.#....####..##..#..#..##..####.",
+		];
+		assert_eq!(read(&rows), Ok("HEFZUHCZ".to_owned()));
+
+		let mut unknown = rows.map(str::to_owned);
+		unknown[0].replace_range(5..6, ".");
+		assert_eq!(read(&unknown), Err(Unreadable::UnknownGlyph { index: 1 }));
+
+		let err = read(&rows[..4]).unwrap_err();
+		assert_eq!(err, Unreadable::Height(4));
+		assert_eq!(
+			err.to_string(),
+			"Letters are 6 rows high, but the screen has 4"
+		);
+	}
+}
@@ -0,0 +1,330 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use nom::{
+	branch::alt,
+	bytes::complete::tag,
+	character::complete::{char, digit1},
+	combinator::{map, map_res, value},
+	multi::separated_list1,
+	sequence::{delimited, preceded, separated_pair},
+	IResult,
+};
+
+use crate::{ParseError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+	const DAY: u8 = 11;
+
+	type Parsed<'input> = BTreeMap<usize, Monkey>;
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		create_monkey_map(input)
+	}
+
+	fn part1(monkeys: &Self::Parsed<'_>) -> Self::Part1 {
+		let mut monkeys = monkeys.clone();
+		let monkey_ids = monkeys.keys().copied().collect::<Vec<_>>();
+
+		for _ in 0..20 {
+			for monkey_id in &monkey_ids {
+				let items = monkeys
+					.get_mut(monkey_id)
+					.unwrap()
+					.items
+					.drain(..)
+					.collect::<Vec<_>>();
+				for item in items {
+					let (worry_level, to_monkey) = {
+						let monkey = monkeys.get_mut(monkey_id).unwrap();
+						let operation_value = monkey.operation_value.unwrap_or(item);
+						let worry_level = match monkey.operator {
+							Operator::Multiply => operation_value * item,
+							Operator::Add => operation_value + item,
+						} / 3;
+
+						let to_monkey = if worry_level % monkey.test == 0 {
+							monkey.true_cond
+						} else {
+							monkey.false_cond
+						};
+
+						monkey.inspected += 1;
+
+						(worry_level, to_monkey)
+					};
+
+					monkeys.get_mut(&to_monkey).unwrap().items.push(worry_level);
+				}
+			}
+		}
+
+		monkeys
+			.values()
+			.map(|monkey| monkey.inspected)
+			.collect::<BTreeSet<_>>()
+			.iter()
+			.rev()
+			.take(2)
+			.product::<usize>()
+	}
+
+	fn part2(monkeys: &Self::Parsed<'_>) -> Self::Part2 {
+		let mut monkeys = monkeys.clone();
+		let monkey_ids = monkeys.keys().copied().collect::<Vec<_>>();
+		let divisor = monkeys
+			.values()
+			.map(|monkey| monkey.test)
+			.product::<usize>();
+
+		for _ in 0..10000 {
+			for monkey_id in &monkey_ids {
+				let items = monkeys
+					.get_mut(monkey_id)
+					.unwrap()
+					.items
+					.drain(..)
+					.collect::<Vec<_>>();
+				for item in items {
+					let (worry_level, to_monkey) = {
+						let monkey = monkeys.get_mut(monkey_id).unwrap();
+						let operation_value = monkey.operation_value.unwrap_or(item);
+						let worry_level = match monkey.operator {
+							Operator::Multiply => operation_value * item,
+							Operator::Add => operation_value + item,
+						} % divisor;
+
+						let to_monkey = if worry_level % monkey.test == 0 {
+							monkey.true_cond
+						} else {
+							monkey.false_cond
+						};
+
+						monkey.inspected += 1;
+
+						(worry_level, to_monkey)
+					};
+
+					monkeys.get_mut(&to_monkey).unwrap().items.push(worry_level);
+				}
+			}
+		}
+
+		monkeys
+			.values()
+			.map(|monkey| monkey.inspected)
+			.collect::<BTreeSet<_>>()
+			.iter()
+			.rev()
+			.take(2)
+			.product::<usize>()
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+	items: Vec<usize>,
+	operator: Operator,
+	/// `None` when the operation uses the old worry level twice
+	operation_value: Option<usize>,
+	test: usize,
+	true_cond: usize,
+	false_cond: usize,
+	inspected: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+	Add,
+	Multiply,
+}
+
+fn parse_int(val: &str) -> IResult<&str, usize> {
+	map_res(digit1, str::parse)(val)
+}
+
+fn parse_header(line: &str) -> IResult<&str, usize> {
+	delimited(tag("Monkey "), parse_int, char(':'))(line)
+}
+
+fn parse_items(line: &str) -> IResult<&str, Vec<usize>> {
+	preceded(
+		tag("Starting items: "),
+		separated_list1(tag(", "), parse_int),
+	)(line)
+}
+
+fn parse_operation(line: &str) -> IResult<&str, (Operator, Option<usize>)> {
+	preceded(
+		tag("Operation: new = old "),
+		separated_pair(
+			alt((
+				value(Operator::Add, char('+')),
+				value(Operator::Multiply, char('*')),
+			)),
+			char(' '),
+			alt((value(None, tag("old")), map(parse_int, Some))),
+		),
+	)(line)
+}
+
+fn parse_test(line: &str) -> IResult<&str, usize> {
+	preceded(tag("Test: divisible by "), parse_int)(line)
+}
+
+fn parse_true_cond(line: &str) -> IResult<&str, usize> {
+	preceded(tag("If true: throw to monkey "), parse_int)(line)
+}
+
+fn parse_false_cond(line: &str) -> IResult<&str, usize> {
+	preceded(tag("If false: throw to monkey "), parse_int)(line)
+}
+
+/// Applies `parser` to a whole line, ignoring indentation.
+fn parse_line<'l, T>(
+	(number, line): (usize, &'l str),
+	mut parser: impl FnMut(&'l str) -> IResult<&'l str, T>,
+	message: &str,
+) -> Result<T, ParseError> {
+	let (remaining, parsed) = parser(line.trim_start())
+		.map_err(|err| ParseError::nom(Day11::DAY, number, line, err, message))?;
+
+	if !remaining.is_empty() {
+		return Err(ParseError::remaining(
+			Day11::DAY,
+			number,
+			line,
+			remaining.len(),
+			"Unexpected trailing input",
+		));
+	}
+
+	Ok(parsed)
+}
+
+fn create_monkey_map(input: &str) -> Result<BTreeMap<usize, Monkey>, ParseError> {
+	let mut monkeys = BTreeMap::new();
+	// Throw targets, checked once every monkey is known
+	let mut targets = Vec::new();
+
+	let mut lines = input
+		.lines()
+		.enumerate()
+		.map(|(idx, line)| (idx + 1, line))
+		.filter(|(_, line)| !line.trim().is_empty());
+
+	while let Some(header) = lines.next() {
+		let mut next_line = |message: &str| {
+			lines.next().ok_or_else(|| {
+				let (number, line) = header;
+				ParseError::new(Day11::DAY, number, line, line.len(), 0, message)
+			})
+		};
+
+		let monkey_id = parse_line(header, parse_header, "Expected monkey header")?;
+
+		let items_line = next_line("Expected starting items")?;
+		let items = parse_line(items_line, parse_items, "Expected starting items")?;
+
+		let operation_line = next_line("Expected operation")?;
+		let (operator, operation_value) =
+			parse_line(operation_line, parse_operation, "Invalid operation")?;
+
+		let test_line = next_line("Expected test")?;
+		let test = parse_line(test_line, parse_test, "Invalid test")?;
+
+		let true_line = next_line("Expected true condition")?;
+		let true_cond = parse_line(true_line, parse_true_cond, "Invalid true condition")?;
+
+		let false_line = next_line("Expected false condition")?;
+		let false_cond = parse_line(false_line, parse_false_cond, "Invalid false condition")?;
+
+		if test == 0 {
+			let (number, line) = test_line;
+			return Err(ParseError::remaining(
+				Day11::DAY,
+				number,
+				line,
+				1,
+				"Expected a non-zero divisor",
+			));
+		}
+
+		targets.extend([(true_line, true_cond), (false_line, false_cond)]);
+
+		monkeys.insert(
+			monkey_id,
+			Monkey {
+				items,
+				operator,
+				operation_value,
+				test,
+				true_cond,
+				false_cond,
+				inspected: 0,
+			},
+		);
+	}
+
+	if let Some(((number, line), _)) = targets
+		.into_iter()
+		.find(|(_, target)| !monkeys.contains_key(target))
+	{
+		let target_len = line.len() - line.rfind(' ').map_or(0, |idx| idx + 1);
+		return Err(ParseError::remaining(
+			Day11::DAY,
+			number,
+			line,
+			target_len,
+			"Unknown monkey",
+		));
+	}
+
+	Ok(monkeys)
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day11::Day11, Solution};
+
+	const TEST_INPUT: &str = r#"Monkey 0:
+Starting items: 79, 98
+Operation: new = old * 19
+Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+Starting items: 54, 65, 75, 74
+Operation: new = old + 6
+Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+Starting items: 79, 60, 97
+Operation: new = old * old
+Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+Starting items: 74
+Operation: new = old + 3
+Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day11::part1(&Day11::parse(TEST_INPUT).unwrap()), 10605);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day11::part2(&Day11::parse(TEST_INPUT).unwrap()), 2713310158);
+	}
+}
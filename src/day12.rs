@@ -0,0 +1,401 @@
+use std::{collections::VecDeque, error::Error, fmt};
+
+use crate::{
+	grid::Grid,
+	pathfinding::{bfs, dijkstra, Paths},
+	point::{Point2, Vec2},
+	ParseError, Solution,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+	const DAY: u8 = 12;
+
+	type Parsed<'input> = Initialized;
+	type Part1 = Result<usize, Unreachable>;
+	type Part2 = Result<usize, Unreachable>;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		initialize(input)
+	}
+
+	fn part1((start, end, heightmap): &Self::Parsed<'_>) -> Self::Part1 {
+		let end = heightmap.index_of(*end).unwrap();
+
+		steps(
+			heightmap,
+			*start,
+			&ClimbRules::default(),
+			false,
+			|index, _| index == end,
+		)
+		.ok_or(Unreachable::End)
+	}
+
+	fn part2((_, end, heightmap): &Self::Parsed<'_>) -> Self::Part2 {
+		// Work backwards to the nearest 0
+		steps(
+			heightmap,
+			*end,
+			&ClimbRules::default(),
+			true,
+			|_, elevation| elevation == 0,
+		)
+		.ok_or(Unreachable::Lowest)
+	}
+}
+
+/// Breadth-first search over the cells of `heightmap` from `start`, taking the steps `rules`
+/// allow, or their reverse when searching `backwards`, and returning the steps to the first cell
+/// that `is_goal`. Every step counts as one, so this is equivalent to [`climb`] and [`descend`]
+/// when the rules cost one per step, without the hashing.
+fn steps(
+	heightmap: &Grid<isize>,
+	start: Point2,
+	rules: &ClimbRules,
+	backwards: bool,
+	is_goal: impl Fn(usize, isize) -> bool,
+) -> Option<usize> {
+	let (width, height) = (heightmap.width() as isize, heightmap.height() as isize);
+	let elevations = heightmap.as_slice();
+	let directions: &[Vec2] = if rules.diagonal {
+		&Vec2::ALL
+	} else {
+		&Vec2::CARDINALS
+	};
+
+	let mut steps = vec![usize::MAX; elevations.len()];
+	let mut queue = VecDeque::new();
+
+	let start = heightmap.index_of(start)?;
+	steps[start] = 0;
+	queue.push_back(start);
+
+	while let Some(index) = queue.pop_front() {
+		let elevation = elevations[index];
+		if is_goal(index, elevation) {
+			return Some(steps[index]);
+		}
+
+		let (x, y) = (index as isize % width, index as isize / width);
+		for direction in directions {
+			let (x, y) = (x + direction.x, y + direction.y);
+			if !(0..width).contains(&x) || !(0..height).contains(&y) {
+				continue;
+			}
+
+			let neighbor = (y * width + x) as usize;
+			let delta = elevations[neighbor] - elevation;
+			let delta = if backwards { -delta } else { delta };
+			if steps[neighbor] == usize::MAX && rules.allows(delta) {
+				steps[neighbor] = steps[index] + 1;
+				queue.push_back(neighbor);
+			}
+		}
+	}
+
+	None
+}
+
+/// A goal that no route leads to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unreachable {
+	/// The end can't be reached from the start
+	End,
+	/// The end can't be reached from any lowest position
+	Lowest,
+}
+
+impl fmt::Display for Unreachable {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::End => write!(f, "The end (E) can't be reached from the start (S)"),
+			Self::Lowest => write!(f, "The end (E) can't be reached from any lowest position"),
+		}
+	}
+}
+
+impl Error for Unreachable {}
+
+/// Which steps between positions are allowed, and what they cost.
+#[derive(Clone, Copy, Debug)]
+pub struct ClimbRules {
+	/// Highest a single step can climb
+	pub max_ascent: isize,
+	/// Furthest a single step can drop
+	pub max_descent: isize,
+	/// Cost of a step, given the change in elevation
+	pub cost: fn(isize) -> usize,
+	/// Allow steps to diagonal neighbors
+	pub diagonal: bool,
+}
+
+impl Default for ClimbRules {
+	/// The puzzle's rules: climb at most one, drop any distance, one step at a time.
+	fn default() -> Self {
+		Self {
+			max_ascent: 1,
+			max_descent: isize::MAX,
+			cost: |_| 1,
+			diagonal: false,
+		}
+	}
+}
+
+impl ClimbRules {
+	pub fn allows(&self, delta: isize) -> bool {
+		(-self.max_descent..=self.max_ascent).contains(&delta)
+	}
+
+	/// Positions in `heightmap` that `position` has a step to or from, depending on `backwards`.
+	fn neighbors<'h>(
+		&self,
+		heightmap: &'h Grid<isize>,
+		position: Point2,
+		backwards: bool,
+	) -> impl Iterator<Item = Point2> + 'h {
+		let directions: &[Vec2] = if self.diagonal {
+			&Vec2::ALL
+		} else {
+			&Vec2::CARDINALS
+		};
+		let rules = *self;
+
+		directions
+			.iter()
+			.map(move |direction| position + *direction)
+			.filter(move |neighbor| match heightmap.get(*neighbor) {
+				Some(elevation) if backwards => rules.allows(heightmap[position] - elevation),
+				Some(elevation) => rules.allows(elevation - heightmap[position]),
+				None => false,
+			})
+	}
+}
+
+/// Searches from the start towards the end.
+pub fn climb((start, end, heightmap): &Initialized, rules: &ClimbRules) -> Paths<Point2> {
+	dijkstra(
+		[*start],
+		|position| rules.neighbors(heightmap, position, false),
+		|from, to| (rules.cost)(heightmap[to] - heightmap[from]),
+		|position| position == *end,
+	)
+}
+
+/// Searches backwards from the end towards the nearest lowest position.
+pub fn descend((_, end, heightmap): &Initialized, rules: &ClimbRules) -> Paths<Point2> {
+	dijkstra(
+		[*end],
+		|position| rules.neighbors(heightmap, position, true),
+		|from, to| (rules.cost)(heightmap[from] - heightmap[to]),
+		|position| heightmap[position] == 0,
+	)
+}
+
+/// The positions along the shortest route from the start to the end.
+pub fn route(initialized: &Initialized, rules: &ClimbRules) -> Result<Vec<Point2>, Unreachable> {
+	climb(initialized, rules)
+		.path(initialized.1)
+		.ok_or(Unreachable::End)
+}
+
+/// The positions along the shortest route from any lowest position to the end.
+pub fn trail(initialized: &Initialized, rules: &ClimbRules) -> Result<Vec<Point2>, Unreachable> {
+	let paths = descend(initialized, rules);
+	let (lowest, _) = paths.goal.ok_or(Unreachable::Lowest)?;
+	let mut trail = paths.path(lowest).ok_or(Unreachable::Lowest)?;
+	trail.reverse();
+
+	Ok(trail)
+}
+
+/// Whether each position can be reached from the start.
+pub fn reachable((start, _, heightmap): &Initialized, rules: &ClimbRules) -> Grid<bool> {
+	let paths = bfs(
+		[*start],
+		|position| rules.neighbors(heightmap, position, false),
+		|_| false,
+	);
+
+	let mut reachable = Grid::new(heightmap.width(), heightmap.height(), false);
+	for position in paths.costs.keys() {
+		reachable[*position] = true;
+	}
+
+	reachable
+}
+
+/// Draws `route` over `heightmap` like the puzzle statement, with an arrow on each position
+/// pointing to the next one and `E` at the end of the route.
+pub fn render_route(heightmap: &Grid<isize>, route: &[Point2]) -> String {
+	let mut rendered = Grid::new(heightmap.width(), heightmap.height(), '.');
+
+	for step in route.windows(2) {
+		rendered[step[0]] = match step[1] - step[0] {
+			Vec2::UP => '^',
+			Vec2::DOWN => 'v',
+			Vec2::LEFT => '<',
+			Vec2::RIGHT => '>',
+			step if step.x == step.y => '\\',
+			_ => '/',
+		};
+	}
+
+	if let Some(end) = route.last() {
+		rendered[*end] = 'E';
+	}
+
+	rendered.to_string()
+}
+
+/// Start and end positions, and the elevation of every position.
+pub type Initialized = (Point2, Point2, Grid<isize>);
+
+fn initialize(input: &str) -> Result<Initialized, ParseError> {
+	let mut start_node = None;
+	let mut end_node = None;
+
+	let elevation = |b: u8| (b - b'a') as isize;
+
+	let heightmap = Grid::parse(
+		Day12::DAY,
+		input,
+		|position, b| match b {
+			b'S' => {
+				start_node = Some(position);
+				Some(elevation(b'a'))
+			}
+			b'E' => {
+				end_node = Some(position);
+				Some(elevation(b'z'))
+			}
+			b'a'..=b'z' => Some(elevation(b)),
+			_ => None,
+		},
+		"Expected an elevation (a-z), S or E",
+	)?;
+
+	// Missing markers are reported at the end of the input
+	let missing = |marker: &str| {
+		let (number, line) = input
+			.lines()
+			.enumerate()
+			.last()
+			.map(|(idx, line)| (idx + 1, line))
+			.unwrap_or((1, ""));
+		ParseError::new(
+			Day12::DAY,
+			number,
+			line,
+			line.len(),
+			0,
+			format!("Missing {marker} marker"),
+		)
+	};
+
+	Ok((
+		start_node.ok_or_else(|| missing("start (S)"))?,
+		end_node.ok_or_else(|| missing("end (E)"))?,
+		heightmap,
+	))
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{
+		day12::{
+			climb, descend, reachable, render_route, route, steps, trail, ClimbRules, Day12,
+			Unreachable,
+		},
+		Solution,
+	};
+
+	const TEST_INPUT: &str = r#"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day12::part1(&Day12::parse(TEST_INPUT).unwrap()), Ok(31));
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day12::part2(&Day12::parse(TEST_INPUT).unwrap()), Ok(29));
+	}
+
+	#[test]
+	fn test_route() {
+		let rules = ClimbRules::default();
+		let initialized = Day12::parse(TEST_INPUT).unwrap();
+		assert_eq!(route(&initialized, &rules).unwrap().len(), 32);
+		assert_eq!(trail(&initialized, &rules).unwrap().len(), 30);
+
+		let initialized = Day12::parse("Sbcdefghijklm\nEyxwvutsrqpon").unwrap();
+		let route = route(&initialized, &rules).unwrap();
+		assert_eq!(
+			render_route(&initialized.2, &route),
+			">>>>>>>>>>>>v\nE<<<<<<<<<<<<"
+		);
+	}
+
+	#[test]
+	fn test_rules() {
+		let initialized = Day12::parse(TEST_INPUT).unwrap();
+		let (start, end, heightmap) = &initialized;
+		let end_index = heightmap.index_of(*end).unwrap();
+		let cost = |rules| climb(&initialized, &rules).goal.map(|(_, steps)| steps);
+
+		let diagonal = ClimbRules {
+			diagonal: true,
+			..Default::default()
+		};
+		let steep = ClimbRules {
+			max_ascent: 25,
+			..Default::default()
+		};
+		let flat = ClimbRules {
+			max_descent: 0,
+			..Default::default()
+		};
+		for (rules, expected) in [(diagonal, 27), (steep, 7), (flat, 31)] {
+			assert_eq!(cost(rules), Some(expected));
+
+			// The puzzle's search agrees with the general one for any rules costing one a step
+			let forwards = steps(heightmap, *start, &rules, false, |index, _| {
+				index == end_index
+			});
+			assert_eq!(forwards, Some(expected));
+			let backwards = steps(heightmap, *end, &rules, true, |_, elevation| elevation == 0);
+			assert_eq!(
+				backwards,
+				descend(&initialized, &rules).goal.map(|(_, steps)| steps)
+			);
+		}
+
+		let tiring = ClimbRules {
+			cost: |delta| 1 + delta.unsigned_abs(),
+			..Default::default()
+		};
+		assert_eq!(cost(tiring), Some(56));
+	}
+
+	#[test]
+	fn test_unreachable() {
+		let initialized = Day12::parse("Sbcz\nabyE").unwrap();
+		let rules = ClimbRules::default();
+
+		assert_eq!(Day12::part1(&initialized), Err(Unreachable::End));
+		assert_eq!(Day12::part2(&initialized), Err(Unreachable::Lowest));
+		assert_eq!(route(&initialized, &rules), Err(Unreachable::End));
+		assert_eq!(
+			reachable(&initialized, &rules)
+				.render(|reachable| if *reachable { '#' } else { '.' })
+				.join("\n"),
+			"###.\n##.."
+		);
+	}
+}
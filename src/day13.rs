@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+
+use nom::{
+	branch::alt,
+	character::complete::{char, digit1},
+	combinator::{map, map_opt},
+	multi::separated_list0,
+	sequence::delimited,
+	IResult,
+};
+
+use crate::{ParseError, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+	const DAY: u8 = 13;
+
+	type Parsed<'input> = Vec<Item>;
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		let lines = input
+			.lines()
+			.enumerate()
+			.map(|(idx, line)| (idx + 1, line))
+			.filter(|(_, line)| !line.is_empty())
+			.collect::<Vec<_>>();
+
+		let packets = lines
+			.iter()
+			.map(|(number, packet)| {
+				let (remaining, item) = parse_item(packet.as_bytes()).map_err(|err| {
+					ParseError::nom(Self::DAY, *number, packet, err, "Invalid packet")
+				})?;
+
+				if !remaining.is_empty() {
+					return Err(ParseError::remaining(
+						Self::DAY,
+						*number,
+						packet,
+						remaining.len(),
+						"Unexpected trailing input",
+					));
+				}
+
+				Ok(item)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		if let (true, Some((number, packet))) = (packets.len() % 2 != 0, lines.last()) {
+			return Err(ParseError::new(
+				Self::DAY,
+				*number,
+				packet,
+				packet.len(),
+				0,
+				"Expected another packet to complete the pair",
+			));
+		}
+
+		Ok(packets)
+	}
+
+	fn part1(packets: &Self::Parsed<'_>) -> Self::Part1 {
+		packets
+			.chunks(2)
+			.enumerate()
+			.filter_map(|(idx, pair)| match pair[0].cmp(&pair[1]) {
+				Ordering::Less => Some(idx + 1),
+				_ => None,
+			})
+			.sum::<usize>()
+	}
+
+	fn part2(packets: &Self::Parsed<'_>) -> Self::Part2 {
+		let dividers = [
+			Item::List(vec![Item::List(vec![Item::Integer(2)])]),
+			Item::List(vec![Item::List(vec![Item::Integer(6)])]),
+		];
+
+		let list = packets
+			.iter()
+			.filter(|item| *item < &dividers[1])
+			.collect::<Vec<_>>();
+
+		let pos_a = list.iter().filter(|item| **item < &dividers[0]).count() + 1;
+		let pos_b = list.len() + 2;
+
+		pos_a * pos_b
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Item {
+	Integer(usize),
+	List(Vec<Item>),
+}
+
+impl Ord for Item {
+	fn cmp(&self, other: &Self) -> Ordering {
+		match (self, other) {
+			(Item::Integer(left), Item::Integer(right)) => left.cmp(right),
+			(Item::Integer(_), Item::List(right)) if right.len() == 1 => self.cmp(&right[0]),
+			(Item::Integer(left), Item::List(_)) => {
+				Item::List(vec![Item::Integer(*left)]).cmp(other)
+			}
+			(Item::List(_), Item::Integer(right)) => {
+				self.cmp(&Item::List(vec![Item::Integer(*right)]))
+			}
+			(Item::List(left), Item::List(right)) => left.iter().cmp(right),
+		}
+	}
+}
+
+impl PartialOrd for Item {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+fn parse_int(val: &[u8]) -> IResult<&[u8], usize> {
+	map_opt(digit1, atoi::atoi)(val)
+}
+
+fn parse_list(val: &[u8]) -> IResult<&[u8], Vec<Item>> {
+	delimited(char('['), separated_list0(char(','), parse_item), char(']'))(val)
+}
+
+fn parse_item(val: &[u8]) -> IResult<&[u8], Item> {
+	alt((map(parse_int, Item::Integer), map(parse_list, Item::List)))(val)
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day13::Day13, Solution};
+
+	const TEST_INPUT: &str = r#"[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day13::part1(&Day13::parse(TEST_INPUT).unwrap()), 13);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day13::part2(&Day13::parse(TEST_INPUT).unwrap()), 140);
+	}
+}
@@ -0,0 +1,222 @@
+use std::fmt;
+
+use nom::{
+	bytes::complete::tag,
+	character::complete::{char, digit1},
+	combinator::{map, map_opt},
+	multi::separated_list1,
+	sequence::separated_pair,
+	IResult,
+};
+
+use crate::{
+	grid::Grid,
+	point::{Point2, Vec2},
+	ParseError, Solution,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+	const DAY: u8 = 14;
+
+	/// The lowest rock, where sand enters the cave, and the cave, wide enough for the sand to
+	/// settle on the floor.
+	type Parsed<'input> = (isize, Point2, Grid<Tile>);
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		scan_cave(input)
+	}
+
+	fn part1((lowest, source, cave): &Self::Parsed<'_>) -> Self::Part1 {
+		let mut cave = cave.clone();
+
+		flood_cave(&mut cave, *source, *lowest, false)
+	}
+
+	fn part2((lowest, source, cave): &Self::Parsed<'_>) -> Self::Part2 {
+		let mut cave = cave.clone();
+
+		flood_cave(&mut cave, *source, lowest + 1, true) + 1
+	}
+}
+
+const SOURCE: Point2 = Point2::new(500, 0);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+	Air,
+	Rock,
+	Sand,
+}
+
+impl fmt::Display for Tile {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let tile = match self {
+			Tile::Air => '.',
+			Tile::Rock => '#',
+			Tile::Sand => 'o',
+		};
+		write!(f, "{tile}")
+	}
+}
+
+fn parse_row(val: &[u8]) -> IResult<&[u8], Vec<Point2>> {
+	separated_list1(tag(" -> "), parse_point)(val)
+}
+
+fn parse_point(line: &[u8]) -> IResult<&[u8], Point2> {
+	map(
+		separated_pair(parse_int, char(','), parse_int),
+		Point2::from,
+	)(line)
+}
+
+fn parse_int(val: &[u8]) -> IResult<&[u8], isize> {
+	map_opt(digit1, atoi::atoi)(val)
+}
+
+fn scan_cave(input: &str) -> Result<(isize, Point2, Grid<Tile>), ParseError> {
+	let mut lowest = 0;
+	let mut rocks = Vec::new();
+
+	for (idx, line) in input.lines().enumerate() {
+		let (remaining, row) = parse_row(line.as_bytes())
+			.map_err(|err| ParseError::nom(Day14::DAY, idx + 1, line, err, "Invalid rock path"))?;
+
+		if !remaining.is_empty() {
+			return Err(ParseError::remaining(
+				Day14::DAY,
+				idx + 1,
+				line,
+				remaining.len(),
+				"Unexpected trailing input",
+			));
+		}
+
+		for window in row[..].windows(2) {
+			let [from, to]: &[_; 2] = window.try_into().unwrap();
+
+			if from.x != to.x && from.y != to.y {
+				return Err(ParseError::line(
+					Day14::DAY,
+					idx + 1,
+					line,
+					format!(
+						"Diagonal rock path from {},{} to {},{}",
+						from.x, from.y, to.x, to.y
+					),
+				));
+			}
+
+			lowest = lowest.max(from.y.max(to.y));
+
+			let step = (*to - *from).signum();
+			rocks
+				.extend((0..=from.chebyshev(*to) as isize).map(|distance| *from + step * distance));
+		}
+	}
+
+	// Sand on the floor spreads at most one column per row either side of the source, so the
+	// cave is shifted to start at its leftmost column
+	let floor = lowest + 2;
+	let left = rocks
+		.iter()
+		.map(|rock| rock.x)
+		.chain([SOURCE.x - floor])
+		.min()
+		.unwrap_or_default();
+	let right = rocks
+		.iter()
+		.map(|rock| rock.x)
+		.chain([SOURCE.x + floor])
+		.max()
+		.unwrap_or_default();
+	let offset = Vec2::new(left, 0);
+
+	let mut cave = Grid::new((right - left + 1) as usize, floor as usize, Tile::Air);
+	for rock in rocks {
+		cave[rock - offset] = Tile::Rock;
+	}
+
+	Ok((lowest, SOURCE - offset, cave))
+}
+
+/// Drops sand from `source` until it falls forever or blocks the source, returning how many
+/// grains came to rest. Sand reaching row `target` rests there if `break_on_target` is set, and
+/// falls forever otherwise.
+pub fn flood_cave(
+	cave: &mut Grid<Tile>,
+	source: Point2,
+	target: isize,
+	break_on_target: bool,
+) -> usize {
+	// Each grain follows the previous one's path until it diverges, so it is dropped from the last
+	// position on that path which is still open
+	let mut path = vec![source];
+	let mut count = 0;
+
+	'sand: while let Some(&position) = path.last() {
+		if position.y == target {
+			if !break_on_target {
+				break;
+			}
+		} else {
+			for direction in [
+				Vec2::DOWN,
+				Vec2::DOWN + Vec2::LEFT,
+				Vec2::DOWN + Vec2::RIGHT,
+			] {
+				let next = position + direction;
+
+				// Sand leaving the side of the cave falls forever
+				let Some(tile) = cave.get(next) else {
+					break 'sand;
+				};
+
+				if *tile == Tile::Air {
+					path.push(next);
+					continue 'sand;
+				}
+			}
+		}
+
+		if position == source {
+			break;
+		}
+
+		cave[position] = Tile::Sand;
+		path.pop();
+		count += 1;
+	}
+
+	count
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day14::Day14, Solution};
+
+	const TEST_INPUT: &str = r#"498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day14::part1(&Day14::parse(TEST_INPUT).unwrap()), 24);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day14::part2(&Day14::parse(TEST_INPUT).unwrap()), 93);
+	}
+
+	#[test]
+	fn test_deep_floor() {
+		// The pile on the floor spreads past x = 0
+		let parsed = Day14::parse("499,600 -> 501,600").unwrap();
+		assert_eq!(Day14::part1(&parsed), 1);
+		assert_eq!(Day14::part2(&parsed), 362400);
+	}
+}
@@ -0,0 +1,182 @@
+use nom::{
+	branch::alt, character::complete::char, combinator::value, sequence::separated_pair, IResult,
+};
+
+use crate::{ParseError, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+	const DAY: u8 = 2;
+
+	type Parsed<'input> = Vec<(Shape, Response)>;
+	type Part1 = u32;
+	type Part2 = u32;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		input
+			.lines()
+			.enumerate()
+			.map(|(idx, line)| {
+				let (remaining, parsed_match) = parse_match(line).map_err(|err| {
+					ParseError::nom(Self::DAY, idx + 1, line, err, "Invalid match")
+				})?;
+
+				if !remaining.is_empty() {
+					return Err(ParseError::remaining(
+						Self::DAY,
+						idx + 1,
+						line,
+						remaining.len(),
+						"Unexpected trailing input",
+					));
+				}
+
+				Ok(parsed_match)
+			})
+			.collect()
+	}
+
+	fn part1(matches: &Self::Parsed<'_>) -> Self::Part1 {
+		matches
+			.iter()
+			.map(|(left, right)| score(*left, Shape::from(*right)))
+			.sum()
+	}
+
+	fn part2(matches: &Self::Parsed<'_>) -> Self::Part2 {
+		matches
+			.iter()
+			.map(|(left, play)| {
+				let right = Play::from(*play).shape(*left);
+
+				score(*left, right)
+			})
+			.sum()
+	}
+}
+
+const SEPARATOR: char = ' ';
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+	Rock = 1,
+	Paper = 2,
+	Scissors = 3,
+}
+
+/// The second column of the strategy guide, which is read as a [`Shape`] in part 1 and as a
+/// [`Play`] in part 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Response {
+	X,
+	Y,
+	Z,
+}
+
+impl From<Response> for Shape {
+	fn from(value: Response) -> Self {
+		match value {
+			Response::X => Self::Rock,
+			Response::Y => Self::Paper,
+			Response::Z => Self::Scissors,
+		}
+	}
+}
+
+pub enum Play {
+	Lose,
+	Draw,
+	Win,
+}
+
+impl Play {
+	pub fn shape(&self, opponent_shape: Shape) -> Shape {
+		match self {
+			Self::Lose => match opponent_shape {
+				Shape::Rock => Shape::Scissors,
+				Shape::Paper => Shape::Rock,
+				Shape::Scissors => Shape::Paper,
+			},
+			Self::Draw => opponent_shape,
+			Self::Win => match opponent_shape {
+				Shape::Rock => Shape::Paper,
+				Shape::Paper => Shape::Scissors,
+				Shape::Scissors => Shape::Rock,
+			},
+		}
+	}
+}
+
+impl From<Response> for Play {
+	fn from(value: Response) -> Self {
+		match value {
+			Response::X => Self::Lose,
+			Response::Y => Self::Draw,
+			Response::Z => Self::Win,
+		}
+	}
+}
+
+fn parse_shape(line: &str) -> IResult<&str, Shape> {
+	alt((
+		value(Shape::Rock, char('A')),
+		value(Shape::Paper, char('B')),
+		value(Shape::Scissors, char('C')),
+	))(line)
+}
+
+fn parse_response(line: &str) -> IResult<&str, Response> {
+	alt((
+		value(Response::X, char('X')),
+		value(Response::Y, char('Y')),
+		value(Response::Z, char('Z')),
+	))(line)
+}
+
+fn parse_match(line: &str) -> IResult<&str, (Shape, Response)> {
+	separated_pair(parse_shape, char(SEPARATOR), parse_response)(line)
+}
+
+pub fn score(left: Shape, right: Shape) -> u32 {
+	let match_score = if left == right {
+		3
+	} else {
+		match (&left, &right) {
+			(Shape::Rock, Shape::Paper)
+			| (Shape::Paper, Shape::Scissors)
+			| (Shape::Scissors, Shape::Rock) => 6,
+			_ => 0,
+		}
+	};
+
+	match_score + right as u32
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day2::Day2, Solution};
+
+	const TEST_INPUT: &str = r#"A Y
+B X
+C Z
+"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day2::part1(&Day2::parse(TEST_INPUT).unwrap()), 15);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day2::part2(&Day2::parse(TEST_INPUT).unwrap()), 12);
+	}
+
+	#[test]
+	fn test_invalid_match() {
+		let err = Day2::parse("A Y\nB Q\n").unwrap_err();
+
+		assert_eq!((err.line, err.column), (2, 3));
+		assert_eq!(err.text, "Q");
+	}
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeSet;
+
+use crate::{ParseError, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+	const DAY: u8 = 3;
+
+	type Parsed<'input> = Vec<&'input str>;
+	type Part1 = u32;
+	type Part2 = u32;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		let rucksacks = input
+			.lines()
+			.enumerate()
+			.map(|(idx, line)| {
+				if let Some(offset) = line.find(|item: char| !item.is_ascii_alphabetic()) {
+					return Err(ParseError::new(
+						Self::DAY,
+						idx + 1,
+						line,
+						offset,
+						1,
+						"Expected an item type (a-z or A-Z)",
+					));
+				}
+
+				if line.len() % 2 != 0 {
+					return Err(ParseError::line(
+						Self::DAY,
+						idx + 1,
+						line,
+						"Expected two equally sized compartments",
+					));
+				}
+
+				let (left, right) = line.split_at(line.len() / 2);
+				let (left, right) = (items(left), items(right));
+				if left & right == 0 {
+					return Err(ParseError::line(
+						Self::DAY,
+						idx + 1,
+						line,
+						"Expected an item type in both compartments",
+					));
+				}
+
+				Ok((line, left | right))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		for (group, chunk) in rucksacks.chunks(3).enumerate() {
+			let last = group * 3 + chunk.len();
+			let (line, _) = chunk[chunk.len() - 1];
+
+			if chunk.len() < 3 {
+				return Err(ParseError::line(
+					Self::DAY,
+					last,
+					line,
+					"Expected rucksacks in groups of three",
+				));
+			}
+
+			if chunk
+				.iter()
+				.fold(u64::MAX, |shared, (_, items)| shared & items)
+				== 0
+			{
+				return Err(ParseError::line(
+					Self::DAY,
+					last,
+					line,
+					"Expected an item type shared by the group of three ending here",
+				));
+			}
+		}
+
+		Ok(rucksacks.into_iter().map(|(line, _)| line).collect())
+	}
+
+	fn part1(rucksacks: &Self::Parsed<'_>) -> Self::Part1 {
+		rucksacks
+			.iter()
+			.map(|line| {
+				let split_len = line.len() / 2;
+				let left: BTreeSet<char> = line[..split_len].chars().collect();
+				let right: BTreeSet<char> = line[split_len..].chars().collect();
+
+				left.intersection(&right)
+					.map(|common| priority(*common))
+					.next()
+					.expect("Parsing checked both compartments share an item")
+			})
+			.sum()
+	}
+
+	fn part2(rucksacks: &Self::Parsed<'_>) -> Self::Part2 {
+		rucksacks
+			.chunks(3)
+			.map(|chunk| priority(badge(chunk).expect("Parsing checked every group has a badge")))
+			.sum()
+	}
+}
+
+/// The item type carried by every rucksack in `group`.
+fn badge(group: &[&str]) -> Option<char> {
+	group
+		.iter()
+		.map(|line| line.chars().collect::<BTreeSet<char>>())
+		.reduce(|acc, line| acc.intersection(&line).copied().collect())?
+		.first()
+		.copied()
+}
+
+/// The item types in `rucksack` as a bitset indexed by priority, cheap enough to validate with.
+/// Every item must be a-z or A-Z.
+fn items(rucksack: &str) -> u64 {
+	rucksack.bytes().fold(0, |set, item| {
+		let priority = match item {
+			b'A'..=b'Z' => item - b'A' + 27,
+			_ => item - b'a' + 1,
+		};
+		set | 1 << priority
+	})
+}
+
+fn priority(item: char) -> u32 {
+	let priority = if item.is_uppercase() {
+		item as u8 - b'A' + 27
+	} else {
+		item as u8 - b'a' + 1
+	};
+
+	priority as u32
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day3::Day3, Solution};
+
+	const TEST_INPUT: &str = r#"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day3::part1(&Day3::parse(TEST_INPUT).unwrap()), 157);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day3::part2(&Day3::parse(TEST_INPUT).unwrap()), 70);
+	}
+
+	#[test]
+	fn test_invalid() {
+		let err = Day3::parse("abcd").unwrap_err();
+		assert_eq!(err.message, "Expected an item type in both compartments");
+
+		let err = Day3::parse("abca\ndbcd").unwrap_err();
+		assert_eq!(
+			(err.line, err.message.as_str()),
+			(2, "Expected rucksacks in groups of three")
+		);
+
+		let err = Day3::parse("abca\ndbcd\neffe").unwrap_err();
+		assert_eq!(err.line, 3);
+	}
+}
@@ -0,0 +1,116 @@
+use nom::{
+	character::complete::{char, digit1},
+	combinator::{map, map_res},
+	sequence::separated_pair,
+	IResult,
+};
+
+use crate::{ParseError, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+	const DAY: u8 = 4;
+
+	type Parsed<'input> = Vec<(Assignment, Assignment)>;
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		input
+			.lines()
+			.enumerate()
+			.map(|(idx, line)| {
+				let (remaining, pair) = parse_match(line).map_err(|err| {
+					ParseError::nom(Self::DAY, idx + 1, line, err, "Invalid match")
+				})?;
+
+				if !remaining.is_empty() {
+					return Err(ParseError::remaining(
+						Self::DAY,
+						idx + 1,
+						line,
+						remaining.len(),
+						"Unexpected trailing input",
+					));
+				}
+
+				Ok(pair)
+			})
+			.collect()
+	}
+
+	fn part1(pairs: &Self::Parsed<'_>) -> Self::Part1 {
+		pairs
+			.iter()
+			.filter(|(a, b)| a.is_subset_of(b) || b.is_subset_of(a))
+			.count()
+	}
+
+	fn part2(pairs: &Self::Parsed<'_>) -> Self::Part2 {
+		pairs
+			.iter()
+			.filter(|(a, b)| a.overlaps_with(b) || b.overlaps_with(a))
+			.count()
+	}
+}
+
+pub struct Assignment {
+	min: u32,
+	max: u32,
+}
+
+impl Assignment {
+	pub fn is_subset_of(&self, other: &Assignment) -> bool {
+		self.min >= other.min && self.max <= other.max
+	}
+
+	pub fn overlaps_with(&self, other: &Assignment) -> bool {
+		(self.min >= other.min && self.min <= other.max)
+			|| (self.max <= other.max && self.max >= other.min)
+	}
+}
+
+impl From<(u32, u32)> for Assignment {
+	fn from((min, max): (u32, u32)) -> Self {
+		Self { min, max }
+	}
+}
+
+fn parse_section(line: &str) -> IResult<&str, u32> {
+	map_res(digit1, str::parse)(line)
+}
+
+fn parse_assignment(line: &str) -> IResult<&str, Assignment> {
+	map(
+		separated_pair(parse_section, char('-'), parse_section),
+		Assignment::from,
+	)(line)
+}
+
+fn parse_match(line: &str) -> IResult<&str, (Assignment, Assignment)> {
+	separated_pair(parse_assignment, char(','), parse_assignment)(line)
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day4::Day4, Solution};
+
+	const TEST_INPUT: &str = r#"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day4::part1(&Day4::parse(TEST_INPUT).unwrap()), 2);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day4::part2(&Day4::parse(TEST_INPUT).unwrap()), 4);
+	}
+}
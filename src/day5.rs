@@ -0,0 +1,216 @@
+use nom::{
+	bytes::complete::tag,
+	character::complete::digit1,
+	sequence::{preceded, tuple},
+	IResult,
+};
+
+use crate::{ParseError, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+	const DAY: u8 = 5;
+
+	type Parsed<'input> = Procedure;
+	/// The top crates, or which stack ended up empty
+	type Part1 = Result<String, String>;
+	type Part2 = Result<String, String>;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		let mut deck = Vec::new();
+		let mut moves = Vec::new();
+		// Crates left on each stack, to catch moves that lift more than a stack holds
+		let mut heights = Vec::new();
+		let mut building_deck = true;
+		for (idx, line) in input.lines().enumerate() {
+			if line.is_empty() || line.starts_with(" 1") {
+				building_deck = false;
+				continue;
+			}
+
+			if building_deck {
+				for (stack, storage_crate) in line.chars().collect::<Vec<_>>().chunks(4).enumerate()
+				{
+					if deck.len() < stack + 1 {
+						deck.push(vec![]);
+					}
+
+					if let Some(storage_crate) = storage_crate.get(1).filter(|c| **c != ' ') {
+						deck[stack].push(*storage_crate);
+					}
+				}
+			} else {
+				let (remaining, (count, from, to)) = parse_move(line).map_err(|err| {
+					ParseError::nom(Self::DAY, idx + 1, line, err, "Invalid move")
+				})?;
+
+				if !remaining.is_empty() {
+					return Err(ParseError::remaining(
+						Self::DAY,
+						idx + 1,
+						line,
+						remaining.len(),
+						"Unexpected trailing input",
+					));
+				}
+
+				let stack = |token: &str| {
+					token
+						.parse::<usize>()
+						.ok()
+						.filter(|stack| (1..=deck.len()).contains(stack))
+						.ok_or_else(|| {
+							ParseError::token(Self::DAY, idx + 1, line, token, "Invalid stack")
+						})
+				};
+
+				let next = Move {
+					count: count.parse().map_err(|_| {
+						ParseError::token(Self::DAY, idx + 1, line, count, "Invalid crate count")
+					})?,
+					from: stack(from)?,
+					to: stack(to)?,
+				};
+
+				if heights.is_empty() {
+					heights = deck.iter().map(Vec::len).collect();
+				}
+				let available = heights[next.from - 1];
+				if next.count > available {
+					return Err(ParseError::token(
+						Self::DAY,
+						idx + 1,
+						line,
+						count,
+						format!("Stack {} only has {available} crates", next.from),
+					));
+				}
+				heights[next.from - 1] -= next.count;
+				heights[next.to - 1] += next.count;
+
+				moves.push(next);
+			}
+		}
+
+		Ok(Procedure { deck, moves })
+	}
+
+	fn part1(procedure: &Self::Parsed<'_>) -> Self::Part1 {
+		procedure.rearrange(true)
+	}
+
+	fn part2(procedure: &Self::Parsed<'_>) -> Self::Part2 {
+		procedure.rearrange(false)
+	}
+}
+
+/// Stacks of crates, with the top crate of each stack first, and the moves to apply to them.
+#[derive(Clone, Debug)]
+pub struct Procedure {
+	deck: Vec<Vec<char>>,
+	moves: Vec<Move>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Move {
+	count: usize,
+	from: usize,
+	to: usize,
+}
+
+impl Procedure {
+	/// Applies every move and returns the top crate of each stack. When `one_at_a_time` is set,
+	/// lifted crates are moved individually and so end up in reverse order.
+	pub fn rearrange(&self, one_at_a_time: bool) -> Result<String, String> {
+		let mut deck = self.deck.clone();
+
+		for Move { count, from, to } in &self.moves {
+			// Parsing checked every stack exists and holds enough crates
+			let from_stack = &mut deck[from - 1];
+			let mut lifted = if one_at_a_time {
+				from_stack.drain(..count).rev().collect::<Vec<_>>()
+			} else {
+				from_stack.drain(..count).collect::<Vec<_>>()
+			};
+
+			let to_stack = &mut deck[to - 1];
+			lifted.append(to_stack);
+			*to_stack = lifted;
+		}
+
+		deck.iter()
+			.enumerate()
+			.map(|(idx, stack)| {
+				stack
+					.first()
+					.ok_or(format!("Stack {} ends up empty", idx + 1))
+			})
+			.collect()
+	}
+}
+
+fn parse_move(line: &str) -> IResult<&str, (&str, &str, &str)> {
+	tuple((
+		preceded(tag("move "), digit1),
+		preceded(tag(" from "), digit1),
+		preceded(tag(" to "), digit1),
+	))(line)
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day5::Day5, Solution};
+
+	const TEST_INPUT: &str = r#"    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(
+			Day5::part1(&Day5::parse(TEST_INPUT).unwrap()),
+			Ok("CMZ".to_owned())
+		);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(
+			Day5::part2(&Day5::parse(TEST_INPUT).unwrap()),
+			Ok("MCD".to_owned())
+		);
+	}
+
+	#[test]
+	fn test_invalid_stack() {
+		let input = TEST_INPUT.replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+		let err = Day5::parse(&input).unwrap_err();
+
+		assert_eq!((err.line, err.column), (9, 18));
+		assert_eq!(err.text, "4");
+	}
+
+	#[test]
+	fn test_too_many_crates() {
+		let input = TEST_INPUT.replace("move 1 from 2 to 1", "move 9 from 2 to 1");
+		let err = Day5::parse(&input).unwrap_err();
+
+		assert_eq!((err.line, err.text.as_str()), (6, "9"));
+		assert_eq!(err.message, "Stack 2 only has 3 crates");
+
+		let input = TEST_INPUT.replace("move 1 from 1 to 2", "move 3 from 3 to 1");
+		let procedure = Day5::parse(&input).unwrap();
+		assert_eq!(
+			Day5::part1(&procedure),
+			Err("Stack 2 ends up empty".to_owned())
+		);
+	}
+}
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use crate::{ParseError, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+	const DAY: u8 = 6;
+
+	type Parsed<'input> = Vec<char>;
+	type Part1 = Result<usize, &'static str>;
+	type Part2 = Result<usize, &'static str>;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		Ok(input.chars().collect())
+	}
+
+	fn part1(signal: &Self::Parsed<'_>) -> Self::Part1 {
+		find_marker(signal, 4).ok_or("No start-of-packet marker found")
+	}
+
+	fn part2(signal: &Self::Parsed<'_>) -> Self::Part2 {
+		find_marker(signal, 14).ok_or("No start-of-message marker found")
+	}
+}
+
+/// The number of characters up to the end of the first `marker_count` different characters.
+pub fn find_marker(signal: &[char], marker_count: usize) -> Option<usize> {
+	signal
+		.windows(marker_count)
+		.position(|window| HashSet::<&char>::from_iter(window.iter()).len() == marker_count)
+		.map(|idx| idx + marker_count)
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day6::Day6, Solution};
+
+	const TEST_INPUT: [(&str, usize, usize); 5] = [
+		("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+		("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+		("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+		("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+		("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+	];
+
+	#[test]
+	fn test_part1() {
+		TEST_INPUT.iter().for_each(|(input, expect_end_marker, _)| {
+			assert_eq!(
+				Day6::part1(&Day6::parse(input).unwrap()),
+				Ok(*expect_end_marker)
+			);
+		});
+	}
+
+	#[test]
+	fn test_part2() {
+		TEST_INPUT
+			.iter()
+			.for_each(|(input, _, expect_start_marker)| {
+				assert_eq!(
+					Day6::part2(&Day6::parse(input).unwrap()),
+					Ok(*expect_start_marker)
+				);
+			});
+	}
+
+	#[test]
+	fn test_no_marker() {
+		let signal = Day6::parse("aaaa").unwrap();
+		assert_eq!(Day6::part1(&signal), Err("No start-of-packet marker found"));
+		assert_eq!(
+			Day6::part2(&signal),
+			Err("No start-of-message marker found")
+		);
+	}
+}
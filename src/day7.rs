@@ -0,0 +1,801 @@
+use std::{
+	collections::{BTreeMap, HashSet},
+	fmt::Write,
+	ops::Deref,
+};
+
+use nom::{
+	bytes::complete::{take_till1, take_until},
+	character::complete::space0,
+	sequence::tuple,
+	IResult,
+};
+
+use crate::{ParseError, Solution};
+
+pub mod generate;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+	const DAY: u8 = 7;
+
+	type Parsed<'input> = Dirs;
+	type Part1 = usize;
+	type Part2 = Result<usize, &'static str>;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		Dirs::try_from(input)
+	}
+
+	fn part1(dirs: &Self::Parsed<'_>) -> Self::Part1 {
+		dirs.iter()
+			.map(|item| item.total_size)
+			.filter(|size| size < &100_000)
+			.sum()
+	}
+
+	fn part2(dirs: &Self::Parsed<'_>) -> Self::Part2 {
+		Cleanup::default()
+			.smallest_dir(dirs)
+			.map(|plan| plan.freed)
+			.ok_or("No directory frees enough space")
+	}
+}
+
+/// A disk's capacity and the free space needed on it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cleanup {
+	pub capacity: usize,
+	pub required: usize,
+}
+
+impl Default for Cleanup {
+	/// The device from the puzzle.
+	fn default() -> Self {
+		Self {
+			capacity: 70_000_000,
+			required: 30_000_000,
+		}
+	}
+}
+
+/// Directories to delete, and the space deleting them frees.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Plan {
+	/// The path and total size of each directory, sorted by path
+	pub dirs: Vec<(String, usize)>,
+	pub freed: usize,
+}
+
+impl Cleanup {
+	/// Space that still has to be freed on a disk holding `dirs`.
+	pub fn to_free(&self, dirs: &Dirs) -> usize {
+		let free = self.capacity.saturating_sub(dirs.root().total_size);
+		self.required.saturating_sub(free)
+	}
+
+	/// The smallest single directory that frees enough space, `None` if even the root doesn't.
+	pub fn smallest_dir(&self, dirs: &Dirs) -> Option<Plan> {
+		let to_free = self.to_free(dirs);
+		if to_free == 0 {
+			return Some(Plan::new(dirs, &[]));
+		}
+
+		let idx = (0..dirs.len())
+			.filter(|idx| dirs[*idx].total_size >= to_free)
+			.min_by_key(|idx| dirs[*idx].total_size)?;
+
+		Some(Plan::new(dirs, &[idx]))
+	}
+
+	/// The directories that free enough space while deleting as little as possible. No directory
+	/// is inside another, since deleting a directory deletes everything below it.
+	pub fn smallest_set(&self, dirs: &Dirs) -> Option<Plan> {
+		let to_free = self.to_free(dirs);
+
+		// Only sets deleting less than the smallest single directory are worth finding
+		let single = self.smallest_dir(dirs)?;
+		if single.freed == to_free {
+			return Some(single);
+		}
+
+		// Each directory's index in preorder, and the preorder index after its subtree. Deleting a
+		// directory skips past its subtree, so every set of directories reachable by skipping or
+		// deleting each one in turn has none inside another.
+		let mut preorder = Vec::with_capacity(dirs.len());
+		let mut ends = vec![0; dirs.len()];
+		let mut stack = vec![(0, false)];
+		while let Some((idx, visited)) = stack.pop() {
+			if visited {
+				ends[idx] = preorder.len();
+				continue;
+			}
+
+			preorder.push(idx);
+			stack.push((idx, true));
+			stack.extend(dirs[idx].dirs.values().rev().map(|child| (*child, false)));
+		}
+
+		// What the directories from each preorder position on can delete at most, by deleting the
+		// files directly inside each of them
+		let mut remaining = vec![0; preorder.len() + 1];
+		for (position, idx) in preorder.iter().enumerate().rev() {
+			let own = dirs[*idx].files.values().sum::<usize>();
+			remaining[position] = remaining[position + 1] + own;
+		}
+
+		// Branch and bound over deleting or skipping each directory in preorder, depth first with
+		// deleting tried first. Each branch keeps how many of `chosen` are its own, and the
+		// directory it adds to them, so no set is ever copied.
+		let mut best = single.freed;
+		let mut best_idxs = None;
+		let mut chosen = Vec::new();
+		let mut branches = vec![(0, 0, 0, None)];
+		while let Some((position, freed, count, deleted)) = branches.pop() {
+			chosen.truncate(count);
+			chosen.extend(deleted);
+
+			if freed >= to_free {
+				if freed < best {
+					best = freed;
+					best_idxs = Some(chosen.clone());
+				}
+				if best == to_free {
+					break;
+				}
+				continue;
+			}
+
+			if freed + remaining[position] < to_free {
+				continue;
+			}
+
+			let idx = preorder[position];
+			branches.push((position + 1, freed, chosen.len(), None));
+			if freed + dirs[idx].total_size < best {
+				branches.push((
+					ends[idx],
+					freed + dirs[idx].total_size,
+					chosen.len(),
+					Some(idx),
+				));
+			}
+		}
+
+		match best_idxs {
+			Some(idxs) => Some(Plan::new(dirs, &idxs)),
+			None => Some(single),
+		}
+	}
+}
+
+impl Plan {
+	fn new(dirs: &Dirs, idxs: &[usize]) -> Self {
+		let mut planned = idxs
+			.iter()
+			.map(|idx| (dirs.path(*idx), dirs[*idx].total_size))
+			.collect::<Vec<_>>();
+		planned.sort_unstable();
+
+		Self {
+			freed: planned.iter().map(|(_, size)| size).sum(),
+			dirs: planned,
+		}
+	}
+}
+
+/// Every directory in the filesystem, with the root first. Directories refer to each other by
+/// their index.
+#[derive(Debug)]
+pub struct Dirs {
+	inner: Vec<Dir>,
+}
+
+impl Deref for Dirs {
+	type Target = Vec<Dir>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+
+impl Dirs {
+	pub fn root(&self) -> &Dir {
+		&self.inner[0]
+	}
+
+	/// The directory at an absolute `path` like `/a/e`.
+	pub fn get(&self, path: &str) -> Option<&Dir> {
+		path.strip_prefix('/')?
+			.split('/')
+			.filter(|name| !name.is_empty())
+			.try_fold(self.root(), |dir, name| {
+				dir.dirs.get(name).map(|idx| &self.inner[*idx])
+			})
+	}
+
+	/// The absolute path of the directory at `idx`.
+	pub fn path(&self, idx: usize) -> String {
+		let mut names = Vec::new();
+		let mut current = Some(idx);
+		while let Some(idx) = current {
+			let dir = &self.inner[idx];
+			names.push(dir.name.as_str());
+			current = dir.parent;
+		}
+
+		// The root's name is empty
+		names.reverse();
+		match names.join("/") {
+			path if path.is_empty() => "/".to_owned(),
+			path => path,
+		}
+	}
+
+	/// Every directory and file, in name order, like the listing in the puzzle statement:
+	///
+	/// ```text
+	/// - / (dir)
+	///   - a (dir)
+	///     - f (file, size=29116)
+	/// ```
+	pub fn tree(&self) -> String {
+		let mut tree = String::new();
+		self.write_tree(&mut tree, 0, 0);
+		tree
+	}
+
+	fn write_tree(&self, tree: &mut String, idx: usize, depth: usize) {
+		let dir = &self.inner[idx];
+		let name = if dir.parent.is_none() { "/" } else { &dir.name };
+		let _ = writeln!(tree, "{:indent$}- {name} (dir)", "", indent = depth * 2);
+
+		let mut entries = dir
+			.dirs
+			.iter()
+			.map(|(name, idx)| (name, Ok(*idx)))
+			.chain(dir.files.iter().map(|(name, size)| (name, Err(*size))))
+			.collect::<Vec<_>>();
+		entries.sort_unstable_by_key(|(name, _)| *name);
+
+		for (name, entry) in entries {
+			match entry {
+				Ok(idx) => self.write_tree(tree, idx, depth + 1),
+				Err(size) => {
+					let _ = writeln!(
+						tree,
+						"{:indent$}- {name} (file, size={size})",
+						"",
+						indent = (depth + 1) * 2
+					);
+				}
+			}
+		}
+	}
+
+	/// The path and total size of every directory, largest first.
+	pub fn du(&self) -> Vec<(String, usize)> {
+		let mut sizes = (0..self.len())
+			.map(|idx| (self.path(idx), self.inner[idx].total_size))
+			.collect::<Vec<_>>();
+		sizes.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+		sizes
+	}
+
+	/// The path and size of the `count` largest files, largest first.
+	pub fn largest_files(&self, count: usize) -> Vec<(String, usize)> {
+		let mut files = self
+			.iter()
+			.enumerate()
+			.flat_map(|(idx, dir)| {
+				let path = self.path(idx);
+				dir.files.iter().map(move |(name, size)| {
+					let separator = if path.ends_with('/') { "" } else { "/" };
+					(format!("{path}{separator}{name}"), *size)
+				})
+			})
+			.collect::<Vec<_>>();
+		files.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+		files.truncate(count);
+		files
+	}
+
+	/// The child directory `name` of the directory at `parent`, created if it doesn't exist yet.
+	fn child(&mut self, parent: usize, name: &str) -> usize {
+		if let Some(idx) = self.inner[parent].dirs.get(name) {
+			return *idx;
+		}
+
+		let idx = self.inner.len();
+		self.inner.push(Dir::new(name, Some(parent)));
+		self.inner[parent].dirs.insert(name.to_owned(), idx);
+
+		idx
+	}
+}
+
+impl<'input> TryFrom<&'input str> for Dirs {
+	type Error = ParseError;
+
+	/// Parses a transcript, working around any anomalies.
+	fn try_from(input: &'input str) -> Result<Self, Self::Error> {
+		Ok(Self::parse(input)?.0)
+	}
+}
+
+impl Dirs {
+	/// Parses a transcript, also returning the anomalies that were worked around: listing output
+	/// without an `ls`, an entry listed twice or with a different size than before, a `cd` into a
+	/// directory that was never listed, a `cd ..` at the root, and a directory name containing `/`.
+	pub fn parse(input: &str) -> Result<(Self, Vec<ParseError>), ParseError> {
+		let mut dirs = Self {
+			inner: vec![Dir::new("", None)],
+		};
+		let mut anomalies = Vec::new();
+
+		// Directories that have appeared in a listing, besides the root
+		let mut listed = HashSet::new();
+		// Names in the current listing, `None` outside of one
+		let mut listing: Option<HashSet<&str>> = None;
+
+		let mut current_key = 0usize;
+
+		for (idx, line) in input.lines().enumerate() {
+			let anomaly = |token, message: String| {
+				ParseError::token(Day7::DAY, idx + 1, line, token, message)
+			};
+
+			if let Some(command) = line.strip_prefix('$') {
+				let command = Command::try_from(command.trim()).map_err(|(token, message)| {
+					ParseError::token(Day7::DAY, idx + 1, line, token, message)
+				})?;
+
+				listing = None;
+
+				match command {
+					Command::ChangeDir(ChangeDirCommand::Root) => current_key = 0,
+					Command::ChangeDir(ChangeDirCommand::MoveUp(token)) => {
+						match dirs[current_key].parent {
+							Some(parent) => current_key = parent,
+							None => anomalies
+								.push(anomaly(token, "Already at the root directory".to_owned())),
+						}
+					}
+					Command::ChangeDir(ChangeDirCommand::MoveIn(name)) => {
+						current_key = dirs.child(current_key, name);
+
+						if name.contains('/') {
+							anomalies.push(anomaly(name, unaddressable(name)));
+						} else if !listed.contains(&current_key) {
+							anomalies.push(anomaly(name, format!("`{name}` was never listed")));
+						}
+					}
+					Command::List => listing = Some(HashSet::new()),
+				}
+
+				continue;
+			}
+
+			let Some(names) = &mut listing else {
+				anomalies.push(ParseError::line(
+					Day7::DAY,
+					idx + 1,
+					line,
+					"Output without a preceding `ls`",
+				));
+				continue;
+			};
+
+			let name = if let Some(name) = line.strip_prefix("dir ") {
+				listed.insert(dirs.child(current_key, name));
+				if name.contains('/') {
+					anomalies.push(anomaly(name, unaddressable(name)));
+				}
+				name
+			} else {
+				let file = File::try_from(line).map_err(|token| {
+					ParseError::token(Day7::DAY, idx + 1, line, token, "Expected file size")
+				})?;
+
+				// Listing a directory again lists the same files
+				let files = &mut dirs.inner[current_key].files;
+				if let Some(size) = files.insert(file.name.to_owned(), file.size) {
+					if size != file.size && !names.contains(file.name) {
+						let message =
+							format!("`{}` was listed earlier with size {size}", file.name);
+						anomalies.push(anomaly(file.size_text, message));
+					}
+				}
+
+				file.name
+			};
+
+			if !names.insert(name) {
+				anomalies.push(anomaly(name, format!("`{name}` is listed twice")));
+			}
+		}
+
+		// Children always come after their parent, so every child's total is known before it is
+		// added to its parent
+		for idx in (0..dirs.len()).rev() {
+			let dir = &mut dirs.inner[idx];
+			dir.total_size += dir.files.values().sum::<usize>();
+
+			if let Some(parent) = dir.parent {
+				let total_size = dir.total_size;
+				dirs.inner[parent].total_size += total_size;
+			}
+		}
+
+		Ok((dirs, anomalies))
+	}
+
+	/// Parses a transcript, refusing any anomaly.
+	pub fn parse_strict(input: &str) -> Result<Self, ParseError> {
+		let (dirs, anomalies) = Self::parse(input)?;
+		match anomalies.into_iter().next() {
+			Some(anomaly) => Err(anomaly),
+			None => Ok(dirs),
+		}
+	}
+}
+
+fn unaddressable(name: &str) -> String {
+	format!("`{name}` contains `/`, so its path can't be looked up")
+}
+
+/// `size` in bytes, kibibytes, mebibytes and so on like `du -h`, with one decimal place below 10.
+pub fn human_size(size: usize) -> String {
+	const UNITS: [&str; 5] = ["K", "M", "G", "T", "P"];
+
+	let mut value = size as f64;
+	let mut unit = "";
+	for next in UNITS {
+		if value < 1024.0 {
+			break;
+		}
+
+		value /= 1024.0;
+		unit = next;
+	}
+
+	match unit {
+		"" => size.to_string(),
+		_ if value < 10.0 => format!("{value:.1}{unit}"),
+		_ => format!("{value:.0}{unit}"),
+	}
+}
+
+#[derive(Debug)]
+pub struct Dir {
+	/// Empty for the root
+	pub name: String,
+	pub parent: Option<usize>,
+	/// Index of each child directory, by name
+	pub dirs: BTreeMap<String, usize>,
+	/// Size of each file, by name
+	pub files: BTreeMap<String, usize>,
+	/// Size of every file in this directory and below
+	pub total_size: usize,
+}
+
+impl Dir {
+	fn new(name: &str, parent: Option<usize>) -> Self {
+		Self {
+			name: name.to_owned(),
+			parent,
+			dirs: BTreeMap::new(),
+			files: BTreeMap::new(),
+			total_size: 0,
+		}
+	}
+}
+
+#[derive(Debug)]
+struct File<'item> {
+	size: usize,
+	/// The size as written in the transcript
+	size_text: &'item str,
+	name: &'item str,
+}
+
+impl<'item> TryFrom<&'item str> for File<'item> {
+	/// The offending part of the line
+	type Error = &'item str;
+
+	fn try_from(value: &'item str) -> Result<Self, Self::Error> {
+		let parse_result: IResult<&str, &str> = take_until(" ")(value);
+		let (name, size) = parse_result.map_err(|_| value)?;
+
+		Ok(Self {
+			size: size.parse().map_err(|_| size)?,
+			size_text: size,
+			name: name.trim_start(),
+		})
+	}
+}
+
+#[derive(Debug)]
+enum Command<'item> {
+	ChangeDir(ChangeDirCommand<'item>),
+	List,
+}
+
+impl<'item> TryFrom<&'item str> for Command<'item> {
+	/// The offending part of the line, and what is wrong with it
+	type Error = (&'item str, &'static str);
+
+	fn try_from(value: &'item str) -> Result<Self, Self::Error> {
+		let parse_result: IResult<&str, (&str, &str)> =
+			tuple((take_till1(char::is_whitespace), space0))(value);
+		let (argument, (command, _)) = parse_result.map_err(|_| (value, "Expected a command"))?;
+
+		match (command, argument) {
+			("cd", "") => Err((command, "Expected a directory")),
+			("cd", argument) => Ok(Self::ChangeDir(ChangeDirCommand::from(argument))),
+			("ls", "") => Ok(Self::List),
+			("ls", argument) => Err((argument, "Unexpected trailing input")),
+			_ => Err((command, "Unexpected command")),
+		}
+	}
+}
+
+#[derive(Debug)]
+enum ChangeDirCommand<'item> {
+	Root,
+	/// With the `..` token, for reporting
+	MoveUp(&'item str),
+	MoveIn(&'item str),
+}
+
+impl<'item> From<&'item str> for ChangeDirCommand<'item> {
+	fn from(value: &'item str) -> Self {
+		match value {
+			"/" => Self::Root,
+			".." => Self::MoveUp(value),
+			name => Self::MoveIn(name),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{
+		day7::{human_size, Cleanup, Day7, Dirs, Plan},
+		Solution,
+	};
+
+	const TEST_INPUT: &str = r#"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day7::part1(&Day7::parse(TEST_INPUT).unwrap()), 95437);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day7::part2(&Day7::parse(TEST_INPUT).unwrap()), Ok(24933642));
+	}
+
+	#[test]
+	fn test_tree() {
+		let dirs = Dirs::try_from(TEST_INPUT).unwrap();
+
+		assert_eq!(dirs.get("/a/e").unwrap().total_size, 584);
+		assert_eq!(dirs.get("/d").unwrap().files["d.log"], 8033020);
+		assert!(dirs.get("/a/x").is_none());
+		assert_eq!(dirs.path(dirs.root().dirs["a"]), "/a");
+
+		// Revisiting a directory, from the root, doesn't count its files twice
+		let revisited = format!("{TEST_INPUT}\n$ cd /\n$ cd a\n$ ls\n29116 f\n$ cd e\n$ ls\n584 i");
+		let revisited = Dirs::try_from(revisited.as_str()).unwrap();
+		assert_eq!(revisited.len(), dirs.len());
+		assert_eq!(revisited.root().total_size, 48381165);
+	}
+
+	#[test]
+	fn test_reports() {
+		let dirs = Dirs::try_from(TEST_INPUT).unwrap();
+
+		assert_eq!(
+			dirs.tree(),
+			r#"- / (dir)
+  - a (dir)
+    - e (dir)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir)
+    - d.ext (file, size=5626152)
+    - d.log (file, size=8033020)
+    - j (file, size=4060174)
+    - k (file, size=7214296)
+"#
+		);
+		assert_eq!(
+			dirs.du(),
+			[
+				("/".to_owned(), 48381165),
+				("/d".to_owned(), 24933642),
+				("/a".to_owned(), 94853),
+				("/a/e".to_owned(), 584)
+			]
+		);
+		assert_eq!(
+			dirs.largest_files(2),
+			[
+				("/b.txt".to_owned(), 14848514),
+				("/c.dat".to_owned(), 8504156)
+			]
+		);
+		assert_eq!(
+			[584, 94853, 24933642].map(human_size),
+			["584", "93K", "24M"]
+		);
+	}
+
+	#[test]
+	fn test_anomalies() {
+		let (_, anomalies) = Dirs::parse(TEST_INPUT).unwrap();
+		assert!(anomalies.is_empty());
+
+		let transcript = r#"$ cd /
+$ cd ..
+$ ls
+dir a
+10 b
+10 b
+$ cd a
+$ cd ..
+$ cd c
+20 d
+$ cd /
+$ ls
+11 b"#;
+
+		let (dirs, anomalies) = Dirs::parse(transcript).unwrap();
+		assert_eq!(dirs.root().total_size, 11);
+		assert_eq!(
+			anomalies
+				.iter()
+				.map(|anomaly| (anomaly.line, anomaly.column, anomaly.message.as_str()))
+				.collect::<Vec<_>>(),
+			[
+				(2, 6, "Already at the root directory"),
+				(6, 4, "`b` is listed twice"),
+				(9, 6, "`c` was never listed"),
+				(10, 1, "Output without a preceding `ls`"),
+				(13, 1, "`b` was listed earlier with size 10"),
+			]
+		);
+
+		let err = Dirs::parse_strict(transcript).unwrap_err();
+		assert_eq!(err.line, 2);
+
+		let (_, anomalies) = Dirs::parse("$ cd /\n$ ls\ndir a/b\n$ cd a/b").unwrap();
+		assert_eq!(
+			anomalies
+				.iter()
+				.map(|anomaly| (anomaly.line, anomaly.message.as_str()))
+				.collect::<Vec<_>>(),
+			[
+				(3, "`a/b` contains `/`, so its path can't be looked up"),
+				(4, "`a/b` contains `/`, so its path can't be looked up"),
+			]
+		);
+
+		for (command, token, message) in [
+			("$ cdfoo", "cdfoo", "Unexpected command"),
+			("$ lsx", "lsx", "Unexpected command"),
+			("$ ls x", "x", "Unexpected trailing input"),
+			("$ cd", "cd", "Expected a directory"),
+		] {
+			let err = Dirs::parse(command).unwrap_err();
+			assert_eq!((err.text.as_str(), err.message.as_str()), (token, message));
+		}
+	}
+
+	#[test]
+	fn test_cleanup() {
+		let dirs = Dirs::try_from(
+			r#"$ cd /
+$ ls
+dir x
+dir y
+dir z
+$ cd x
+$ ls
+10 f
+$ cd ..
+$ cd y
+$ ls
+20 f
+$ cd ..
+$ cd z
+$ ls
+35 f"#,
+		)
+		.unwrap();
+
+		let cleanup = Cleanup {
+			capacity: 100,
+			required: 65,
+		};
+		let plan = |planned: &[(&str, usize)]| Plan {
+			dirs: planned
+				.iter()
+				.map(|(path, size)| (path.to_string(), *size))
+				.collect(),
+			freed: planned.iter().map(|(_, size)| size).sum(),
+		};
+
+		assert_eq!(cleanup.to_free(&dirs), 30);
+		assert_eq!(cleanup.smallest_dir(&dirs), Some(plan(&[("/z", 35)])));
+		assert_eq!(
+			cleanup.smallest_set(&dirs),
+			Some(plan(&[("/x", 10), ("/y", 20)]))
+		);
+
+		let cleanup = Cleanup {
+			capacity: 100,
+			required: 35,
+		};
+		assert_eq!(cleanup.smallest_set(&dirs), Some(plan(&[])));
+
+		let cleanup = Cleanup {
+			capacity: 50,
+			required: 100,
+		};
+		assert_eq!(cleanup.smallest_dir(&dirs), None);
+		assert_eq!(cleanup.smallest_set(&dirs), None);
+
+		// The search doesn't depend on how many bytes there are
+		let dirs = Dirs::try_from(
+			"$ cd /\n$ ls\ndir a\ndir b\ndir c\n$ cd a\n$ ls\n6000000000 f\n$ cd ..\n$ cd b\n$ ls\n5000000000 f\n$ cd ..\n$ cd c\n$ ls\n4000000000 f",
+		)
+		.unwrap();
+		let cleanup = Cleanup {
+			capacity: 20_000_000_000,
+			required: 14_000_000_000,
+		};
+		assert_eq!(
+			cleanup.smallest_set(&dirs),
+			Some(plan(&[("/b", 5_000_000_000), ("/c", 4_000_000_000)]))
+		);
+
+		// Directories whose names can't be looked up by path are still planned
+		let dirs = Dirs::try_from("$ cd /\n$ ls\ndir a/b\n70 f\n$ cd a/b\n$ ls\n60 g").unwrap();
+		let cleanup = Cleanup {
+			capacity: 150,
+			required: 60,
+		};
+		assert_eq!(cleanup.smallest_dir(&dirs), Some(plan(&[("/a/b", 60)])));
+		assert_eq!(cleanup.smallest_set(&dirs), Some(plan(&[("/a/b", 60)])));
+	}
+}
@@ -0,0 +1,365 @@
+use std::{
+	collections::{BTreeMap, VecDeque},
+	fmt::Write,
+	fs, io,
+	path::Path,
+};
+
+/// A directory tree to write a terminal transcript for.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tree {
+	pub dirs: BTreeMap<String, Tree>,
+	/// Size of each file, by name
+	pub files: BTreeMap<String, usize>,
+}
+
+/// The shape of a random [`Tree`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RandomSpec {
+	/// Levels of directories below the root
+	pub depth: usize,
+	/// Most directories in any one directory
+	pub max_dirs: usize,
+	/// Most files in any one directory
+	pub max_files: usize,
+	/// Largest file size, with every file empty if it's 0
+	pub max_size: usize,
+}
+
+impl Default for RandomSpec {
+	/// Roughly the size of a puzzle input.
+	fn default() -> Self {
+		Self {
+			depth: 5,
+			max_dirs: 4,
+			max_files: 5,
+			max_size: 300_000,
+		}
+	}
+}
+
+/// The order directories are visited and listed in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Order {
+	/// Enter each directory as soon as its parent is listed, in name order, like the puzzle
+	#[default]
+	DepthFirst,
+	/// List every directory at one level before any at the next, changing to each from the root
+	BreadthFirst,
+	/// Depth first, with directories and listings shuffled
+	Random,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+	pub order: Order,
+	/// Directories to change to and list again once every directory has been listed
+	pub revisits: usize,
+	/// Seed for shuffling and picking directories to revisit
+	pub seed: u64,
+}
+
+impl Tree {
+	/// Reads the directory at `path` from disk. Symlinks, and entries whose names can't be
+	/// written in a transcript, are skipped.
+	pub fn read(path: &Path) -> io::Result<Self> {
+		let mut tree = Self::default();
+
+		for entry in fs::read_dir(path)? {
+			let entry = entry?;
+			let file_type = entry.file_type()?;
+
+			let Some(name) = entry.file_name().to_str().map(str::to_owned) else {
+				continue;
+			};
+			// The parser trims commands and splits listings on whitespace
+			if name != name.trim() || name.contains(char::is_control) {
+				continue;
+			}
+
+			if file_type.is_dir() {
+				tree.dirs.insert(name, Self::read(&entry.path())?);
+			} else if file_type.is_file() {
+				tree.files.insert(name, entry.metadata()?.len() as usize);
+			}
+		}
+
+		Ok(tree)
+	}
+
+	/// A random tree shaped by `spec`.
+	pub fn random(spec: &RandomSpec, seed: u64) -> Self {
+		Self::random_level(spec, spec.depth, &mut Rng(seed))
+	}
+
+	fn random_level(spec: &RandomSpec, depth: usize, rng: &mut Rng) -> Self {
+		let mut tree = Self::default();
+
+		for _ in 0..rng.below(spec.max_files + 1) {
+			let mut name = rng.name();
+			if rng.below(2) == 0 {
+				let extension = rng.name();
+				name.push('.');
+				name.push_str(&extension[..extension.len().min(3)]);
+			}
+
+			if !tree.dirs.contains_key(&name) {
+				let size = (rng.below(spec.max_size) + 1).min(spec.max_size);
+				tree.files.insert(name, size);
+			}
+		}
+
+		if depth > 0 {
+			for _ in 0..rng.below(spec.max_dirs + 1) {
+				let name = rng.name();
+				if !tree.files.contains_key(&name) {
+					let dir = Self::random_level(spec, depth - 1, rng);
+					tree.dirs.insert(name, dir);
+				}
+			}
+		}
+
+		tree
+	}
+
+	/// The total size of the files in and below every directory, by absolute path.
+	pub fn sizes(&self) -> BTreeMap<String, usize> {
+		let mut sizes = BTreeMap::new();
+		self.add_sizes("/".to_owned(), &mut sizes);
+		sizes
+	}
+
+	fn add_sizes(&self, path: String, sizes: &mut BTreeMap<String, usize>) -> usize {
+		let mut total = self.files.values().sum::<usize>();
+
+		for (name, dir) in &self.dirs {
+			let separator = if path.ends_with('/') { "" } else { "/" };
+			total += dir.add_sizes(format!("{path}{separator}{name}"), sizes);
+		}
+
+		sizes.insert(path, total);
+		total
+	}
+
+	/// Every directory's path from the root, as names.
+	fn paths(&self) -> Vec<Vec<&str>> {
+		let mut paths = vec![Vec::new()];
+		let mut idx = 0;
+		while idx < paths.len() {
+			let path = paths[idx].clone();
+			let dir = path.iter().fold(self, |dir, name| &dir.dirs[*name]);
+			paths.extend(dir.dirs.keys().map(|name| {
+				let mut path = path.clone();
+				path.push(name.as_str());
+				path
+			}));
+			idx += 1;
+		}
+
+		paths
+	}
+}
+
+/// Writes a terminal transcript that explores `tree`, in the puzzle's format.
+pub fn transcript(tree: &Tree, options: &Options) -> String {
+	let mut rng = Rng(options.seed);
+	let mut transcript = "$ cd /\n".to_owned();
+	let shuffle = options.order == Order::Random;
+
+	match options.order {
+		Order::DepthFirst | Order::Random => depth_first(tree, &mut transcript, &mut rng, shuffle),
+		Order::BreadthFirst => {
+			let mut queue = VecDeque::from([(Vec::new(), tree)]);
+			while let Some((path, dir)) = queue.pop_front() {
+				if !path.is_empty() {
+					change_dir(&mut transcript, &path);
+				}
+				list(dir, &mut transcript, &mut rng, false);
+
+				queue.extend(dir.dirs.iter().map(|(name, child)| {
+					let mut path = path.clone();
+					path.push(name.as_str());
+					(path, child)
+				}));
+			}
+		}
+	}
+
+	if options.revisits > 0 {
+		let paths = tree.paths();
+		for _ in 0..options.revisits {
+			let path = &paths[rng.below(paths.len())];
+			change_dir(&mut transcript, path);
+			let dir = path.iter().fold(tree, |dir, name| &dir.dirs[*name]);
+			list(dir, &mut transcript, &mut rng, shuffle);
+		}
+	}
+
+	transcript
+}
+
+fn depth_first(tree: &Tree, transcript: &mut String, rng: &mut Rng, shuffle: bool) {
+	list(tree, transcript, rng, shuffle);
+
+	let mut dirs = tree.dirs.iter().collect::<Vec<_>>();
+	if shuffle {
+		rng.shuffle(&mut dirs);
+	}
+
+	for (name, dir) in dirs {
+		let _ = writeln!(transcript, "$ cd {name}");
+		depth_first(dir, transcript, rng, shuffle);
+		transcript.push_str("$ cd ..\n");
+	}
+}
+
+/// Changes to `path` from the root.
+fn change_dir(transcript: &mut String, path: &[&str]) {
+	transcript.push_str("$ cd /\n");
+	for name in path {
+		let _ = writeln!(transcript, "$ cd {name}");
+	}
+}
+
+fn list(tree: &Tree, transcript: &mut String, rng: &mut Rng, shuffle: bool) {
+	let mut entries = tree
+		.dirs
+		.keys()
+		.map(|name| format!("dir {name}"))
+		.chain(
+			tree.files
+				.iter()
+				.map(|(name, size)| format!("{size} {name}")),
+		)
+		.collect::<Vec<_>>();
+	if shuffle {
+		rng.shuffle(&mut entries);
+	}
+
+	transcript.push_str("$ ls\n");
+	for entry in entries {
+		transcript.push_str(&entry);
+		transcript.push('\n');
+	}
+}
+
+/// SplitMix64, so transcripts can be reproduced from a seed without another dependency.
+struct Rng(u64);
+
+impl Rng {
+	fn next(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+		z ^ (z >> 31)
+	}
+
+	/// A number from 0 up to, but not including, `bound`, or 0 if `bound` is 0.
+	fn below(&mut self, bound: usize) -> usize {
+		self.next().checked_rem(bound as u64).unwrap_or(0) as usize
+	}
+
+	fn shuffle<T>(&mut self, items: &mut [T]) {
+		for idx in (1..items.len()).rev() {
+			items.swap(idx, self.below(idx + 1));
+		}
+	}
+
+	/// A lowercase name of one to eight letters.
+	fn name(&mut self) -> String {
+		(0..self.below(8) + 1)
+			.map(|_| (b'a' + self.below(26) as u8) as char)
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::{collections::BTreeMap, fs};
+
+	use crate::day7::{
+		generate::{transcript, Options, Order, RandomSpec, Tree},
+		Dirs,
+	};
+
+	fn round_trip(tree: &Tree, options: &Options) {
+		let transcript = transcript(tree, options);
+		let dirs = Dirs::parse_strict(&transcript).unwrap();
+
+		let sizes = dirs.du().into_iter().collect::<BTreeMap<_, _>>();
+		assert_eq!(sizes, tree.sizes(), "{options:?}");
+	}
+
+	#[test]
+	fn test_random_round_trip() {
+		for seed in 0..20 {
+			let tree = Tree::random(&RandomSpec::default(), seed);
+
+			for order in [Order::DepthFirst, Order::BreadthFirst, Order::Random] {
+				let options = Options {
+					order,
+					revisits: seed as usize,
+					seed,
+				};
+				round_trip(&tree, &options);
+			}
+		}
+	}
+
+	#[test]
+	fn test_empty_files() {
+		let spec = RandomSpec {
+			max_size: 0,
+			..RandomSpec::default()
+		};
+		let tree = Tree::random(&spec, 7);
+
+		assert!(tree.sizes().values().all(|size| *size == 0));
+		round_trip(&tree, &Options::default());
+	}
+
+	#[test]
+	fn test_read_round_trip() {
+		let root = std::env::temp_dir().join(format!("aoc-day7-{}", std::process::id()));
+		let files: [(&str, &[u8]); 3] = [
+			("b.txt", b"14848514"),
+			("a/e/i", &[0; 584]),
+			("a/f", b"29116"),
+		];
+		fs::create_dir_all(root.join("a/e")).unwrap();
+		fs::create_dir_all(root.join("d")).unwrap();
+		// Names a transcript can't hold are skipped
+		fs::create_dir_all(root.join("d/trailing ")).unwrap();
+		fs::create_dir_all(root.join("\tleading")).unwrap();
+		fs::write(root.join("d/line\nbreak"), "1").unwrap();
+		for (path, contents) in files {
+			fs::write(root.join(path), contents).unwrap();
+		}
+
+		let tree = Tree::read(&root);
+		fs::remove_dir_all(&root).unwrap();
+		let tree = tree.unwrap();
+
+		// Each directory's total from the bytes written below it
+		let written = |dir: &str| -> usize {
+			files
+				.iter()
+				.filter(|(path, _)| format!("/{path}").starts_with(dir))
+				.map(|(_, contents)| contents.len())
+				.sum()
+		};
+		let expected = [
+			("/".to_owned(), written("/")),
+			("/a".to_owned(), written("/a/")),
+			("/a/e".to_owned(), written("/a/e/")),
+			("/d".to_owned(), written("/d/")),
+		];
+
+		let dirs = Dirs::parse_strict(&transcript(&tree, &Options::default())).unwrap();
+		let mut sizes = dirs.du();
+		sizes.sort_unstable();
+		assert_eq!(sizes, expected);
+		round_trip(&tree, &Options::default());
+	}
+}
@@ -0,0 +1,89 @@
+use crate::{
+	grid::Grid,
+	point::{Point2, Vec2},
+	ParseError, Solution,
+};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+	const DAY: u8 = 8;
+
+	type Parsed<'input> = Grid<u8>;
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		Grid::parse(
+			Day8::DAY,
+			input,
+			|_, b| atoi::atoi(&[b]),
+			"Expected tree height",
+		)
+	}
+
+	fn part1(trees: &Self::Parsed<'_>) -> Self::Part1 {
+		// Trees on the edge have nothing blocking them in at least one direction
+		trees
+			.iter()
+			.filter(|(position, height)| {
+				Vec2::CARDINALS
+					.iter()
+					.any(|direction| is_visible_from(trees, *position, *direction, height))
+			})
+			.count()
+	}
+
+	fn part2(trees: &Self::Parsed<'_>) -> Self::Part2 {
+		trees
+			.iter()
+			.map(|(position, height)| {
+				Vec2::CARDINALS
+					.iter()
+					.map(|direction| count_til_blocked(trees, position, *direction, height))
+					.product()
+			})
+			.max()
+			.unwrap_or_default()
+	}
+}
+
+fn is_visible_from(trees: &Grid<u8>, position: Point2, direction: Vec2, current: &u8) -> bool {
+	trees
+		.ray(position, direction)
+		.all(|(_, height)| height < current)
+}
+
+fn count_til_blocked(trees: &Grid<u8>, position: Point2, direction: Vec2, current: &u8) -> usize {
+	let mut max = 0usize;
+	for (_, height) in trees.ray(position, direction) {
+		if height >= current {
+			return max + 1;
+		}
+
+		max += 1;
+	}
+
+	max
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day8::Day8, Solution};
+
+	const TEST_INPUT: &str = r#"30373
+25512
+65332
+33549
+35390"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day8::part1(&Day8::parse(TEST_INPUT).unwrap()), 21);
+	}
+
+	#[test]
+	fn test_part2() {
+		assert_eq!(Day8::part2(&Day8::parse(TEST_INPUT).unwrap()), 8);
+	}
+}
@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+
+use nom::{
+	bytes::complete::take,
+	character::complete::{char, digit1},
+	sequence::{preceded, tuple},
+	IResult,
+};
+
+use crate::{
+	point::{Point2, Vec2},
+	ParseError, Solution,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+	const DAY: u8 = 9;
+
+	type Parsed<'input> = Vec<(Direction, isize)>;
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+		input
+			.lines()
+			.enumerate()
+			.map(|(idx, line)| parse_move(idx + 1, line))
+			.collect()
+	}
+
+	fn part1(moves: &Self::Parsed<'_>) -> Self::Part1 {
+		let mut visited: HashSet<Point2> = HashSet::new();
+		visited.insert(Point2::ORIGIN);
+
+		let mut tail = Point2::ORIGIN;
+		let mut head = Point2::ORIGIN;
+
+		for (direction, amount) in moves {
+			let step = Vec2::from(*direction);
+			for _ in 0..*amount {
+				head += step;
+				move_adjacent(&mut tail, head, Some(&mut visited));
+			}
+		}
+
+		visited.len()
+	}
+
+	fn part2(moves: &Self::Parsed<'_>) -> Self::Part2 {
+		const COUNT: usize = 10;
+		let mut visited: HashSet<Point2> = HashSet::new();
+		visited.insert(Point2::ORIGIN);
+
+		let mut rope = [Point2::ORIGIN; COUNT];
+
+		for (direction, amount) in moves {
+			let step = Vec2::from(*direction);
+			for _ in 0..*amount {
+				rope[0] += step;
+
+				for idx in 1..COUNT {
+					let prev = rope[idx - 1];
+
+					let visited = if idx == COUNT - 1 {
+						Some(&mut visited)
+					} else {
+						None
+					};
+					move_adjacent(&mut rope[idx], prev, visited);
+				}
+			}
+		}
+
+		visited.len()
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+	Up,
+	Down,
+	Left,
+	Right,
+}
+
+impl<'item> TryFrom<&'item str> for Direction {
+	/// The offending part of the line
+	type Error = &'item str;
+
+	fn try_from(value: &'item str) -> Result<Self, Self::Error> {
+		match value {
+			"U" => Ok(Self::Up),
+			"D" => Ok(Self::Down),
+			"L" => Ok(Self::Left),
+			"R" => Ok(Self::Right),
+			_ => Err(value),
+		}
+	}
+}
+
+impl From<Direction> for Vec2 {
+	fn from(value: Direction) -> Self {
+		match value {
+			Direction::Up => Vec2::UP,
+			Direction::Down => Vec2::DOWN,
+			Direction::Left => Vec2::LEFT,
+			Direction::Right => Vec2::RIGHT,
+		}
+	}
+}
+
+/// Moves `knot` to touch `other`, if it no longer does.
+fn move_adjacent(knot: &mut Point2, other: Point2, visited: Option<&mut HashSet<Point2>>) {
+	let diff = other - *knot;
+	if diff.chebyshev() > 1 {
+		*knot += diff.signum();
+
+		if let Some(visited) = visited {
+			visited.insert(*knot);
+		}
+	}
+}
+
+fn parse_move(number: usize, line: &str) -> Result<(Direction, isize), ParseError> {
+	let result: IResult<&str, (&str, &str)> =
+		tuple((take(1usize), preceded(char(' '), digit1)))(line);
+	let (remaining, (direction, amount)) =
+		result.map_err(|err| ParseError::nom(Day9::DAY, number, line, err, "Invalid move"))?;
+
+	if !remaining.is_empty() {
+		return Err(ParseError::remaining(
+			Day9::DAY,
+			number,
+			line,
+			remaining.len(),
+			"Unexpected trailing input",
+		));
+	}
+
+	Ok((
+		Direction::try_from(direction).map_err(|token| {
+			ParseError::token(Day9::DAY, number, line, token, "Invalid move character")
+		})?,
+		amount
+			.parse()
+			.map_err(|_| ParseError::token(Day9::DAY, number, line, amount, "Invalid value"))?,
+	))
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{day9::Day9, Solution};
+
+	const TEST_INPUT_PART1: &str = r#"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+"#;
+
+	#[test]
+	fn test_part1() {
+		assert_eq!(Day9::part1(&Day9::parse(TEST_INPUT_PART1).unwrap()), 13);
+	}
+
+	const TEST_INPUT_PART2: [(&str, usize); 2] = [
+		(
+			r#"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+"#,
+			1,
+		),
+		(
+			r#"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+"#,
+			36,
+		),
+	];
+	#[test]
+	fn test_part2() {
+		for (input, count) in TEST_INPUT_PART2 {
+			assert_eq!(Day9::part2(&Day9::parse(input).unwrap()), count);
+		}
+	}
+}
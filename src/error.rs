@@ -0,0 +1,152 @@
+use std::{error::Error, fmt};
+
+use nom::InputLength;
+
+/// An error in a day's puzzle input, pointing at the offending text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+	pub day: u8,
+	/// 1-indexed line number
+	pub line: usize,
+	/// 1-indexed column, in characters
+	pub column: usize,
+	/// The offending text, empty when something is missing at the end of a line
+	pub text: String,
+	/// The full line containing the offending text
+	pub line_text: String,
+	pub message: String,
+}
+
+impl ParseError {
+	/// Error at byte `offset` in `line_text`, covering `len` bytes.
+	pub fn new(
+		day: u8,
+		line: usize,
+		line_text: &str,
+		offset: usize,
+		len: usize,
+		message: impl Into<String>,
+	) -> Self {
+		let offset = offset.min(line_text.len());
+		let end = (offset + len).min(line_text.len());
+
+		Self {
+			day,
+			line,
+			column: line_text[..offset].chars().count() + 1,
+			text: line_text[offset..end].to_owned(),
+			line_text: line_text.to_owned(),
+			message: message.into(),
+		}
+	}
+
+	/// Error covering the whole of `line_text`.
+	pub fn line(day: u8, line: usize, line_text: &str, message: impl Into<String>) -> Self {
+		Self::new(day, line, line_text, 0, line_text.len(), message)
+	}
+
+	/// Error covering `token`, which must be a subslice of `line_text`.
+	pub fn token(
+		day: u8,
+		line: usize,
+		line_text: &str,
+		token: &str,
+		message: impl Into<String>,
+	) -> Self {
+		let offset = token.as_ptr() as usize - line_text.as_ptr() as usize;
+		debug_assert!(offset + token.len() <= line_text.len());
+
+		Self::new(day, line, line_text, offset, token.len(), message)
+	}
+
+	/// Error at the start of the `remaining` unparsed input at the end of `line_text`, covering
+	/// the next word.
+	pub fn remaining(
+		day: u8,
+		line: usize,
+		line_text: &str,
+		remaining: usize,
+		message: impl Into<String>,
+	) -> Self {
+		let offset = line_text.len().saturating_sub(remaining);
+		let len = line_text[offset..]
+			.find(char::is_whitespace)
+			.unwrap_or(line_text.len() - offset);
+
+		Self::new(day, line, line_text, offset, len, message)
+	}
+
+	/// Error at the position where a nom parser of `line_text` failed.
+	pub fn nom<I: InputLength>(
+		day: u8,
+		line: usize,
+		line_text: &str,
+		err: nom::Err<nom::error::Error<I>>,
+		message: impl Into<String>,
+	) -> Self {
+		let remaining = match err {
+			nom::Err::Error(err) | nom::Err::Failure(err) => err.input.input_len(),
+			nom::Err::Incomplete(_) => 0,
+		};
+
+		Self::remaining(day, line, line_text, remaining, message)
+	}
+
+	/// Renders the error with the offending line and carets underneath the offending text.
+	/// `location` names where the input came from, e.g. its path.
+	pub fn diagnostic(&self, location: &str) -> String {
+		let number = self.line.to_string();
+		let gutter = " ".repeat(number.len());
+		let carets = "^".repeat(self.text.chars().count().max(1));
+
+		format!(
+			"{message}\n{gutter}--> {location}:{line}:{column}\n{gutter} |\n{number} | {line_text}\n{gutter} | {padding}{carets}",
+			message = self.message,
+			line = self.line,
+			column = self.column,
+			line_text = self.line_text,
+			padding = " ".repeat(self.column - 1),
+		)
+	}
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"day {}, line {}, column {}: {}",
+			self.day, self.line, self.column, self.message
+		)
+	}
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+	use crate::ParseError;
+
+	#[test]
+	fn test_token() {
+		let line = "move 1 from x to 3";
+		let err = ParseError::token(5, 7, line, &line[12..13], "Invalid stack");
+
+		assert_eq!(err.line, 7);
+		assert_eq!(err.column, 13);
+		assert_eq!(err.text, "x");
+	}
+
+	#[test]
+	fn test_diagnostic() {
+		let err = ParseError::remaining(2, 3, "A Q", 1, "Invalid response `Q`");
+
+		assert_eq!(
+			err.diagnostic("inputs/day2.txt"),
+			r#"Invalid response `Q`
+ --> inputs/day2.txt:3:3
+  |
+3 | A Q
+  |   ^"#
+		);
+	}
+}
@@ -0,0 +1,252 @@
+use std::{
+	fmt,
+	ops::{Index, IndexMut},
+};
+
+use crate::{
+	point::{Point2, Vec2},
+	ParseError,
+};
+
+/// A rectangular grid of cells, stored row by row, with `(0, 0)` at the top left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+	width: usize,
+	height: usize,
+	cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+	/// A `width` by `height` grid with every cell set to `value`.
+	pub fn new(width: usize, height: usize, value: T) -> Self
+	where
+		T: Clone,
+	{
+		Self {
+			width,
+			height,
+			cells: vec![value; width * height],
+		}
+	}
+
+	/// Parses one row per line of `input`, converting each byte with `cell`. Rows must all be the
+	/// same length.
+	pub fn parse(
+		day: u8,
+		input: &str,
+		mut cell: impl FnMut(Point2, u8) -> Option<T>,
+		message: &str,
+	) -> Result<Self, ParseError> {
+		let mut width = None;
+		let mut cells = Vec::with_capacity(input.len());
+		let mut height = 0;
+
+		for (y, line) in input.lines().enumerate() {
+			let expected = *width.get_or_insert(line.len());
+			if line.len() != expected {
+				return Err(ParseError::new(
+					day,
+					y + 1,
+					line,
+					expected.min(line.len()),
+					line.len().saturating_sub(expected),
+					format!("Expected {expected} columns in every row"),
+				));
+			}
+
+			for (x, byte) in line.bytes().enumerate() {
+				let value = cell(Point2::new(x as isize, y as isize), byte)
+					.ok_or_else(|| ParseError::new(day, y + 1, line, x, 1, message))?;
+				cells.push(value);
+			}
+
+			height += 1;
+		}
+
+		Ok(Self {
+			width: width.unwrap_or_default(),
+			height,
+			cells,
+		})
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn contains(&self, position: Point2) -> bool {
+		self.index_of(position).is_some()
+	}
+
+	pub fn get(&self, position: Point2) -> Option<&T> {
+		self.index_of(position).map(|index| &self.cells[index])
+	}
+
+	pub fn get_mut(&mut self, position: Point2) -> Option<&mut T> {
+		self.index_of(position).map(|index| &mut self.cells[index])
+	}
+
+	/// Every cell, row by row.
+	pub fn as_slice(&self) -> &[T] {
+		&self.cells
+	}
+
+	/// The index of `position` in [`Grid::as_slice`], if it is inside the grid.
+	pub fn index_of(&self, Point2 { x, y }: Point2) -> Option<usize> {
+		let x = usize::try_from(x).ok().filter(|x| *x < self.width)?;
+		let y = usize::try_from(y).ok().filter(|y| *y < self.height)?;
+		Some(y * self.width + x)
+	}
+
+	/// Positions directly left, right, above and below `position` that are inside the grid.
+	pub fn neighbors4(&self, position: Point2) -> impl Iterator<Item = Point2> + '_ {
+		Vec2::CARDINALS
+			.into_iter()
+			.map(move |direction| position + direction)
+			.filter(|neighbor| self.contains(*neighbor))
+	}
+
+	/// Positions surrounding `position`, including diagonals, that are inside the grid.
+	pub fn neighbors8(&self, position: Point2) -> impl Iterator<Item = Point2> + '_ {
+		Vec2::ALL
+			.into_iter()
+			.map(move |direction| position + direction)
+			.filter(|neighbor| self.contains(*neighbor))
+	}
+
+	/// Cells from `position` in steps of `direction` until the edge of the grid, excluding the
+	/// cell at `position`.
+	pub fn ray(
+		&self,
+		position: Point2,
+		direction: Vec2,
+	) -> impl Iterator<Item = (Point2, &T)> + '_ {
+		(1..)
+			.map(move |distance| position + direction * distance)
+			.map_while(|position| Some((position, self.get(position)?)))
+	}
+
+	pub fn row(&self, y: usize) -> &[T] {
+		&self.cells[y * self.width..(y + 1) * self.width]
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+		// `chunks` panics on a zero width
+		self.cells.chunks(self.width.max(1))
+	}
+
+	pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+		self.cells.iter().skip(x).step_by(self.width.max(1))
+	}
+
+	/// Every cell with its position, row by row.
+	pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> + '_ {
+		self.cells.iter().enumerate().map(|(index, cell)| {
+			let position =
+				Point2::new((index % self.width) as isize, (index / self.width) as isize);
+			(position, cell)
+		})
+	}
+
+	/// Renders one line per row, converting each cell with `cell`.
+	pub fn render(&self, cell: impl Fn(&T) -> char) -> Vec<String> {
+		self.rows()
+			.map(|row| row.iter().map(&cell).collect())
+			.collect()
+	}
+}
+
+impl<T> Index<Point2> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, position: Point2) -> &Self::Output {
+		self.get(position).expect("Position out of bounds")
+	}
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+	fn index_mut(&mut self, position: Point2) -> &mut Self::Output {
+		self.get_mut(position).expect("Position out of bounds")
+	}
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (y, row) in self.rows().enumerate() {
+			if y > 0 {
+				writeln!(f)?;
+			}
+
+			for cell in row {
+				write!(f, "{cell}")?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{
+		grid::Grid,
+		point::{Point2, Vec2},
+	};
+
+	fn digits(input: &str) -> Grid<u32> {
+		Grid::parse(
+			0,
+			input,
+			|_, byte| (byte as char).to_digit(10),
+			"Expected digit",
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn test_parse() {
+		let grid = digits("123\n456");
+
+		assert_eq!((grid.width(), grid.height()), (3, 2));
+		assert_eq!(grid[Point2::new(2, 1)], 6);
+		assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+		assert_eq!(grid.to_string(), "123\n456");
+
+		let err = Grid::parse(0, "123\n4567", |_, byte| Some(byte), "").unwrap_err();
+		assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "7"));
+
+		let err = Grid::parse(
+			0,
+			"123\n4x6",
+			|_, byte| (byte as char).to_digit(10),
+			"Expected digit",
+		)
+		.unwrap_err();
+		assert_eq!(
+			(err.line, err.column, err.message.as_str()),
+			(2, 2, "Expected digit")
+		);
+	}
+
+	#[test]
+	fn test_neighbors() {
+		let grid = digits("123\n456\n789");
+
+		assert_eq!(
+			grid.neighbors4(Point2::ORIGIN).collect::<Vec<_>>(),
+			[Point2::new(1, 0), Point2::new(0, 1)]
+		);
+		assert_eq!(grid.neighbors8(Point2::ORIGIN).count(), 3);
+		assert_eq!(grid.neighbors8(Point2::new(1, 1)).count(), 8);
+		assert_eq!(
+			grid.ray(Point2::ORIGIN, Vec2::DOWN + Vec2::RIGHT)
+				.map(|(_, cell)| *cell)
+				.collect::<Vec<_>>(),
+			[5, 9]
+		);
+	}
+}
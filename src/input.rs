@@ -0,0 +1,70 @@
+use std::{
+	error::Error,
+	fmt, io,
+	io::Read,
+	path::{Path, PathBuf},
+};
+
+/// Environment variable used to override the directory containing `day<N>.txt` inputs.
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+	/// `day<N>.txt` inside a directory of inputs
+	Dir(PathBuf),
+	/// A single explicit file
+	File(PathBuf),
+	Stdin,
+}
+
+impl InputSource {
+	pub fn read(&self, day: u8) -> Result<String, InputError> {
+		let result = match self {
+			Self::Dir(dir) => std::fs::read_to_string(day_path(dir, day)),
+			Self::File(path) => std::fs::read_to_string(path),
+			Self::Stdin => {
+				let mut input = String::new();
+				io::stdin().read_to_string(&mut input).map(|_| input)
+			}
+		};
+
+		result.map_err(|source| InputError {
+			location: self.location(day),
+			source,
+		})
+	}
+
+	/// Human readable location of the input for `day`.
+	pub fn location(&self, day: u8) -> String {
+		match self {
+			Self::Dir(dir) => day_path(dir, day).display().to_string(),
+			Self::File(path) => path.display().to_string(),
+			Self::Stdin => "<stdin>".to_owned(),
+		}
+	}
+}
+
+fn day_path(dir: &Path, day: u8) -> PathBuf {
+	dir.join(format!("day{day}.txt"))
+}
+
+#[derive(Debug)]
+pub struct InputError {
+	location: String,
+	source: io::Error,
+}
+
+impl fmt::Display for InputError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}: {}", self.location, self.source)
+	}
+}
+
+impl Error for InputError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(&self.source)
+	}
+}
@@ -0,0 +1,26 @@
+pub mod answers;
+pub mod bench;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+mod error;
+pub mod grid;
+pub mod input;
+pub mod pathfinding;
+pub mod point;
+pub mod runner;
+mod solution;
+
+pub use error::ParseError;
+pub use solution::{Answer, Solution};
@@ -0,0 +1,217 @@
+use std::{
+	cmp::Reverse,
+	collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+	hash::Hash,
+};
+
+/// The outcome of a search.
+#[derive(Clone, Debug)]
+pub struct Paths<N> {
+	/// The cost to every node reached. Nodes still queued when a goal is found may not have their
+	/// lowest cost.
+	pub costs: HashMap<N, usize>,
+	/// The node each reached node was reached from, missing for the starts.
+	pub predecessors: HashMap<N, N>,
+	/// The first goal reached and the cost to reach it, `None` if no goal is reachable.
+	pub goal: Option<(N, usize)>,
+}
+
+impl<N: Eq + Hash> Paths<N> {
+	pub fn cost(&self, node: &N) -> Option<usize> {
+		self.costs.get(node).copied()
+	}
+
+	/// Every node from a start to `node`, or `None` if `node` wasn't reached.
+	pub fn path(&self, node: N) -> Option<Vec<N>>
+	where
+		N: Copy,
+	{
+		if !self.costs.contains_key(&node) {
+			return None;
+		}
+
+		let mut path =
+			std::iter::successors(Some(node), |node| self.predecessors.get(node).copied())
+				.collect::<Vec<_>>();
+		path.reverse();
+
+		Some(path)
+	}
+}
+
+/// Breadth-first search from every node in `starts` until `is_goal` matches, with every step
+/// costing 1.
+pub fn bfs<N, I>(
+	starts: impl IntoIterator<Item = N>,
+	mut neighbors: impl FnMut(N) -> I,
+	mut is_goal: impl FnMut(N) -> bool,
+) -> Paths<N>
+where
+	N: Copy + Eq + Hash,
+	I: IntoIterator<Item = N>,
+{
+	let mut costs = HashMap::new();
+	let mut predecessors = HashMap::new();
+	let mut queue = VecDeque::new();
+
+	for start in starts {
+		if costs.insert(start, 0).is_none() {
+			queue.push_back((start, 0));
+		}
+	}
+
+	while let Some((node, cost)) = queue.pop_front() {
+		if is_goal(node) {
+			return Paths {
+				costs,
+				predecessors,
+				goal: Some((node, cost)),
+			};
+		}
+
+		for neighbor in neighbors(node) {
+			if let Entry::Vacant(entry) = costs.entry(neighbor) {
+				entry.insert(cost + 1);
+				predecessors.insert(neighbor, node);
+				queue.push_back((neighbor, cost + 1));
+			}
+		}
+	}
+
+	Paths {
+		costs,
+		predecessors,
+		goal: None,
+	}
+}
+
+/// Dijkstra's algorithm from every node in `starts` until `is_goal` matches, where `cost` gives
+/// the cost of stepping between two nodes.
+pub fn dijkstra<N, I>(
+	starts: impl IntoIterator<Item = N>,
+	neighbors: impl FnMut(N) -> I,
+	cost: impl FnMut(N, N) -> usize,
+	is_goal: impl FnMut(N) -> bool,
+) -> Paths<N>
+where
+	N: Copy + Ord + Hash,
+	I: IntoIterator<Item = N>,
+{
+	astar(starts, neighbors, cost, |_| 0, is_goal)
+}
+
+/// A* search from every node in `starts` until `is_goal` matches. `heuristic` estimates the
+/// remaining cost from a node to the nearest goal, and must never overestimate it for the
+/// cheapest path to be found.
+pub fn astar<N, I>(
+	starts: impl IntoIterator<Item = N>,
+	mut neighbors: impl FnMut(N) -> I,
+	mut cost: impl FnMut(N, N) -> usize,
+	mut heuristic: impl FnMut(N) -> usize,
+	mut is_goal: impl FnMut(N) -> bool,
+) -> Paths<N>
+where
+	N: Copy + Ord + Hash,
+	I: IntoIterator<Item = N>,
+{
+	// https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm#Using_a_priority_queue
+	let mut costs = HashMap::new();
+	let mut predecessors = HashMap::new();
+	let mut heap = BinaryHeap::new();
+
+	for start in starts {
+		costs.insert(start, 0);
+		heap.push(Reverse((heuristic(start), 0, start)));
+	}
+
+	while let Some(Reverse((_, steps, node))) = heap.pop() {
+		if steps > costs[&node] {
+			continue;
+		}
+
+		if is_goal(node) {
+			return Paths {
+				costs,
+				predecessors,
+				goal: Some((node, steps)),
+			};
+		}
+
+		for neighbor in neighbors(node) {
+			let next_cost = steps + cost(node, neighbor);
+
+			if costs.get(&neighbor).is_none_or(|known| next_cost < *known) {
+				costs.insert(neighbor, next_cost);
+				predecessors.insert(neighbor, node);
+				heap.push(Reverse((
+					next_cost + heuristic(neighbor),
+					next_cost,
+					neighbor,
+				)));
+			}
+		}
+	}
+
+	Paths {
+		costs,
+		predecessors,
+		goal: None,
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::pathfinding::{astar, bfs, dijkstra};
+
+	/// A line of nodes 0 to 9, where stepping onto an even node costs 1 and an odd node costs 3.
+	fn neighbors(node: usize) -> impl Iterator<Item = usize> {
+		[
+			node.checked_sub(1),
+			Some(node + 1).filter(|next| *next < 10),
+		]
+		.into_iter()
+		.flatten()
+	}
+
+	fn cost(_: usize, to: usize) -> usize {
+		if to.is_multiple_of(2) {
+			1
+		} else {
+			3
+		}
+	}
+
+	#[test]
+	fn test_bfs() {
+		let paths = bfs([0], neighbors, |node| node == 9);
+		assert_eq!(paths.goal, Some((9, 9)));
+		assert_eq!(paths.path(3), Some(vec![0, 1, 2, 3]));
+
+		// The nearest of several goals from the nearest of several starts
+		let paths = bfs([0, 7], neighbors, |node| node == 4 || node == 9);
+		assert_eq!(paths.goal, Some((9, 2)));
+
+		let paths = bfs([0], neighbors, |_| false);
+		assert_eq!((paths.goal, paths.cost(&7)), (None, Some(7)));
+	}
+
+	#[test]
+	fn test_weighted() {
+		let paths = dijkstra([0], neighbors, cost, |node| node == 4);
+		assert_eq!(paths.goal, Some((4, 8)));
+		assert_eq!(paths.path(9), None);
+
+		let paths = astar(
+			[0],
+			neighbors,
+			cost,
+			|node| 4usize.abs_diff(node),
+			|node| node == 4,
+		);
+		assert_eq!(paths.goal, Some((4, 8)));
+
+		let paths = dijkstra([5], neighbors, cost, |node| node == 0 || node == 9);
+		assert_eq!(paths.goal, Some((9, 8)));
+		assert_eq!(paths.path(9), Some(vec![5, 6, 7, 8, 9]));
+	}
+}
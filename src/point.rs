@@ -0,0 +1,197 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a 2D plane, with `x` increasing to the right and `y` increasing downwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+	pub x: isize,
+	pub y: isize,
+}
+
+/// The difference between two [`Point2`]s.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+	pub x: isize,
+	pub y: isize,
+}
+
+impl Point2 {
+	pub const ORIGIN: Self = Self::new(0, 0);
+
+	pub const fn new(x: isize, y: isize) -> Self {
+		Self { x, y }
+	}
+
+	pub fn manhattan(self, other: Self) -> usize {
+		(other - self).manhattan()
+	}
+
+	pub fn chebyshev(self, other: Self) -> usize {
+		(other - self).chebyshev()
+	}
+}
+
+impl Vec2 {
+	pub const ZERO: Self = Self::new(0, 0);
+	pub const UP: Self = Self::new(0, -1);
+	pub const DOWN: Self = Self::new(0, 1);
+	pub const LEFT: Self = Self::new(-1, 0);
+	pub const RIGHT: Self = Self::new(1, 0);
+
+	/// Left, right, up and down.
+	pub const CARDINALS: [Self; 4] = [Self::LEFT, Self::RIGHT, Self::UP, Self::DOWN];
+
+	/// Every direction including diagonals, clockwise from up and to the left.
+	pub const ALL: [Self; 8] = [
+		Self::new(-1, -1),
+		Self::UP,
+		Self::new(1, -1),
+		Self::RIGHT,
+		Self::new(1, 1),
+		Self::DOWN,
+		Self::new(-1, 1),
+		Self::LEFT,
+	];
+
+	pub const fn new(x: isize, y: isize) -> Self {
+		Self { x, y }
+	}
+
+	/// Steps needed when moving only horizontally or vertically.
+	pub fn manhattan(self) -> usize {
+		self.x.unsigned_abs() + self.y.unsigned_abs()
+	}
+
+	/// Steps needed when diagonal moves are allowed.
+	pub fn chebyshev(self) -> usize {
+		self.x.unsigned_abs().max(self.y.unsigned_abs())
+	}
+
+	/// A step of at most one in each axis towards the same direction.
+	pub fn signum(self) -> Self {
+		Self::new(self.x.signum(), self.y.signum())
+	}
+}
+
+impl From<(isize, isize)> for Point2 {
+	fn from((x, y): (isize, isize)) -> Self {
+		Self::new(x, y)
+	}
+}
+
+impl From<(isize, isize)> for Vec2 {
+	fn from((x, y): (isize, isize)) -> Self {
+		Self::new(x, y)
+	}
+}
+
+impl From<Point2> for Vec2 {
+	/// The offset of `point` from the origin.
+	fn from(point: Point2) -> Self {
+		Self::new(point.x, point.y)
+	}
+}
+
+impl Add<Vec2> for Point2 {
+	type Output = Self;
+
+	fn add(self, rhs: Vec2) -> Self::Output {
+		Self::new(self.x + rhs.x, self.y + rhs.y)
+	}
+}
+
+impl AddAssign<Vec2> for Point2 {
+	fn add_assign(&mut self, rhs: Vec2) {
+		*self = *self + rhs;
+	}
+}
+
+impl Sub<Vec2> for Point2 {
+	type Output = Self;
+
+	fn sub(self, rhs: Vec2) -> Self::Output {
+		Self::new(self.x - rhs.x, self.y - rhs.y)
+	}
+}
+
+impl SubAssign<Vec2> for Point2 {
+	fn sub_assign(&mut self, rhs: Vec2) {
+		*self = *self - rhs;
+	}
+}
+
+impl Sub for Point2 {
+	type Output = Vec2;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		Vec2::new(self.x - rhs.x, self.y - rhs.y)
+	}
+}
+
+impl Add for Vec2 {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		Self::new(self.x + rhs.x, self.y + rhs.y)
+	}
+}
+
+impl AddAssign for Vec2 {
+	fn add_assign(&mut self, rhs: Self) {
+		*self = *self + rhs;
+	}
+}
+
+impl Sub for Vec2 {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self::new(self.x - rhs.x, self.y - rhs.y)
+	}
+}
+
+impl SubAssign for Vec2 {
+	fn sub_assign(&mut self, rhs: Self) {
+		*self = *self - rhs;
+	}
+}
+
+impl Mul<isize> for Vec2 {
+	type Output = Self;
+
+	fn mul(self, rhs: isize) -> Self::Output {
+		Self::new(self.x * rhs, self.y * rhs)
+	}
+}
+
+impl Neg for Vec2 {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		Self::new(-self.x, -self.y)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::point::{Point2, Vec2};
+
+	#[test]
+	fn test_arithmetic() {
+		let point = Point2::new(2, 3);
+
+		assert_eq!(point + Vec2::LEFT * 3, Point2::new(-1, 3));
+		assert_eq!(point - Vec2::UP, Point2::new(2, 4));
+		assert_eq!(Point2::ORIGIN - point, Vec2::new(-2, -3));
+		assert_eq!(-(Vec2::DOWN + Vec2::RIGHT), Vec2::new(-1, -1));
+	}
+
+	#[test]
+	fn test_distance() {
+		let from = Point2::new(1, -2);
+		let to = Point2::new(-3, 4);
+
+		assert_eq!(from.manhattan(to), 10);
+		assert_eq!(from.chebyshev(to), 6);
+		assert_eq!((to - from).signum(), Vec2::new(-1, 1));
+	}
+}
@@ -0,0 +1,190 @@
+use std::{
+	fmt,
+	time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::{
+	bench::{self, BenchOptions, DayBench},
+	day1::Day1,
+	day10::{CrtConfig, Day10},
+	day11::Day11,
+	day12::Day12,
+	day13::Day13,
+	day14::Day14,
+	day2::Day2,
+	day3::Day3,
+	day4::Day4,
+	day5::Day5,
+	day6::Day6,
+	day7::Day7,
+	day8::Day8,
+	day9::Day9,
+	Answer, ParseError, Solution,
+};
+
+/// Every solved day, in order.
+pub const DAYS: &[Day] = &[
+	Day::new::<Day1>(),
+	Day::new::<Day2>(),
+	Day::new::<Day3>(),
+	Day::new::<Day4>(),
+	Day::new::<Day5>(),
+	Day::new::<Day6>(),
+	Day::new::<Day7>(),
+	Day::new::<Day8>(),
+	Day::new::<Day9>(),
+	Day::new::<Day10>(),
+	Day::new::<Day11>(),
+	Day::new::<Day12>(),
+	Day::new::<Day13>(),
+	Day::new::<Day14>(),
+];
+
+pub fn day(number: u8) -> Option<&'static Day> {
+	DAYS.iter().find(|day| day.number == number)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+	One,
+	Two,
+}
+
+impl Serialize for Part {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_u8(match self {
+			Self::One => 1,
+			Self::Two => 2,
+		})
+	}
+}
+
+impl fmt::Display for Part {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.pad(match self {
+			Self::One => "1",
+			Self::Two => "2",
+		})
+	}
+}
+
+/// Answers for a single day, with parsing timed separately from each part.
+#[derive(Clone, Debug, Serialize)]
+pub struct DayResult {
+	pub day: u8,
+	#[serde(rename = "parse_ms", serialize_with = "serialize_ms")]
+	pub parse_elapsed: Duration,
+	pub parts: Vec<PartResult>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PartResult {
+	pub part: Part,
+	pub answer: Answer,
+	#[serde(rename = "elapsed_ms", serialize_with = "serialize_ms")]
+	pub elapsed: Duration,
+	/// The grid letters were read from, only filled in when asked for
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub grid: Option<Vec<String>>,
+}
+
+impl PartResult {
+	/// Fills in [`PartResult::grid`] if the answer was read from one.
+	pub fn show_grid(&mut self) {
+		if let Answer::Letters { rows, .. } = &self.answer {
+			self.grid = Some(rows.clone());
+		}
+	}
+}
+
+fn serialize_ms<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_f64(duration.as_secs_f64() * 1000f64)
+}
+
+/// A type-erased [`Solution`], so days can be selected at runtime.
+pub struct Day {
+	pub number: u8,
+	run: fn(&str, Option<Part>) -> Result<DayResult, ParseError>,
+	bench: fn(&str, &BenchOptions) -> Result<DayBench, ParseError>,
+}
+
+impl Day {
+	pub const fn new<S: Solution>() -> Self {
+		Self {
+			number: S::DAY,
+			run: run::<S>,
+			bench: bench::bench::<S>,
+		}
+	}
+
+	/// Runs a single part, or both parts when `part` is `None`.
+	pub fn run(&self, input: &str, part: Option<Part>) -> Result<DayResult, ParseError> {
+		(self.run)(input, part)
+	}
+
+	pub fn bench(&self, input: &str, options: &BenchOptions) -> Result<DayBench, ParseError> {
+		(self.bench)(input, options)
+	}
+}
+
+fn run<S: Solution>(input: &str, part: Option<Part>) -> Result<DayResult, ParseError> {
+	run_with::<S>(
+		input,
+		part,
+		|parsed| S::part1(parsed).into(),
+		|parsed| S::part2(parsed).into(),
+	)
+}
+
+/// Runs day 10 on a device other than the puzzle's.
+pub fn run_day10(
+	input: &str,
+	part: Option<Part>,
+	config: CrtConfig,
+) -> Result<DayResult, ParseError> {
+	run_with::<Day10>(
+		input,
+		part,
+		|program| Day10::signal_strength(program, config).into(),
+		|program| Day10::screen(program, config).into(),
+	)
+}
+
+fn run_with<'input, S: Solution>(
+	input: &'input str,
+	part: Option<Part>,
+	part1: impl Fn(&S::Parsed<'input>) -> Answer,
+	part2: impl Fn(&S::Parsed<'input>) -> Answer,
+) -> Result<DayResult, ParseError> {
+	let start = Instant::now();
+	let parsed = S::parse(input)?;
+	let parse_elapsed = start.elapsed();
+
+	let mut parts = Vec::new();
+	for current in [Part::One, Part::Two] {
+		if part.is_some_and(|part| part != current) {
+			continue;
+		}
+
+		let start = Instant::now();
+		let answer = match current {
+			Part::One => part1(&parsed),
+			Part::Two => part2(&parsed),
+		};
+
+		parts.push(PartResult {
+			part: current,
+			answer,
+			elapsed: start.elapsed(),
+			grid: None,
+		});
+	}
+
+	Ok(DayResult {
+		day: S::DAY,
+		parse_elapsed,
+		parts,
+	})
+}
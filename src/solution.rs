@@ -0,0 +1,93 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use crate::ParseError;
+
+/// A single day's puzzle. The input is parsed once and the parsed structure is shared by both
+/// parts.
+pub trait Solution {
+	const DAY: u8;
+
+	type Parsed<'input>;
+	type Part1: Into<Answer>;
+	type Part2: Into<Answer>;
+
+	fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+	fn part1(parsed: &Self::Parsed<'_>) -> Self::Part1;
+
+	fn part2(parsed: &Self::Parsed<'_>) -> Self::Part2;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum Answer {
+	Integer(i64),
+	#[serde(rename = "string")]
+	Text(String),
+	/// Multi-line answers, one string per row
+	Grid(Vec<String>),
+	/// Letters read from a grid, serialized as just the letters
+	#[serde(rename = "string", serialize_with = "serialize_letters")]
+	Letters {
+		text: String,
+		rows: Vec<String>,
+	},
+	/// No answer could be found, with the reason why
+	Unsolved(String),
+}
+
+impl fmt::Display for Answer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Integer(value) => write!(f, "{value}"),
+			Self::Text(value) | Self::Letters { text: value, .. } => write!(f, "{value}"),
+			Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+			Self::Unsolved(reason) => write!(f, "unsolved: {reason}"),
+		}
+	}
+}
+
+fn serialize_letters<S: Serializer>(
+	text: &str,
+	_rows: &[String],
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(text)
+}
+
+macro_rules! impl_integer_answer {
+	($($ty:ty),*) => {
+		$(
+			impl From<$ty> for Answer {
+				fn from(value: $ty) -> Self {
+					Self::Integer(value as i64)
+				}
+			}
+		)*
+	};
+}
+
+impl_integer_answer!(u32, usize, isize);
+
+impl From<String> for Answer {
+	fn from(value: String) -> Self {
+		Self::Text(value)
+	}
+}
+
+impl From<Vec<String>> for Answer {
+	fn from(value: Vec<String>) -> Self {
+		Self::Grid(value)
+	}
+}
+
+impl<T: Into<Answer>, E: fmt::Display> From<Result<T, E>> for Answer {
+	fn from(value: Result<T, E>) -> Self {
+		match value {
+			Ok(answer) => answer.into(),
+			Err(err) => Self::Unsolved(err.to_string()),
+		}
+	}
+}